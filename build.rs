@@ -1,7 +1,8 @@
+use std::collections::HashMap;
 use std::env;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader, Result, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 macro_rules! template {
     () => {
@@ -9,78 +10,354 @@ macro_rules! template {
 // Generated by build.rs, DO NOT edit
 
 /// Linux specific error codes defined in `errno.h`.
-#[repr(i32)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum LinuxError {{
+///
+/// Any code in `1..=MAX_ERRNO` is valid, so codes received from FUSE servers,
+/// 9p hosts or other kernels pass through losslessly even if they have no
+/// named constant. The named constants also include the kernel-internal codes
+/// from `include/linux/errno.h` (512 and above), which must never be returned
+/// to user space.
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = \"zerocopy\",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+pub struct LinuxError(i32);
+
+impl LinuxError {{
 {0}\
 }}
 
 impl TryFrom<i32> for LinuxError {{
     type Error = i32;
 
+    /// Fails unless `value` is in `1..=MAX_ERRNO`.
     fn try_from(value: i32) -> Result<Self, Self::Error> {{
-        use self::LinuxError::*;
-        match value {{
-{1}            _ => Err(value),
-        }}
+        Self::new(value).ok_or(value)
     }}
 }}
 
 impl LinuxError {{
-    /// Returns the error description.
-    pub const fn as_str(&self) -> &'static str {{
-        use self::LinuxError::*;
+    /// The largest valid error code, as `MAX_ERRNO` in Linux.
+    pub const MAX_ERRNO: i32 = 4095;
+
+    /// The largest named error code.
+    pub const MAX_CODE: i32 = {4};
+
+    /// Returns the error with code `code`, or `None` if it is not in
+    /// `1..=MAX_ERRNO`.
+    pub const fn new(code: i32) -> Option<Self> {{
+        if code > 0 && code <= Self::MAX_ERRNO {{
+            Some(Self(code))
+        }} else {{
+            None
+        }}
+    }}
+
+    /// Returns the name of the error code, e.g. `\"ENOENT\"`, or `None` if it
+    /// has no named constant.
+    pub const fn name(self) -> Option<&'static str> {{
         match self {{
-{2}        }}
+{1}            _ => None,
+        }}
+    }}
+
+    /// Returns `true` if the error code has a named constant.
+    pub const fn is_known(self) -> bool {{
+        self.name().is_some()
+    }}
+
+    /// Returns the error description, or `\"Unknown error\"` for codes without
+    /// a named constant.
+    ///
+    /// With the `glibc-strerror` feature enabled, this is the same as
+    /// [`LinuxError::strerror`].
+    pub const fn as_str(&self) -> &'static str {{
+        match *self {{
+{2}            _ => \"Unknown error\",
+        }}
+    }}
+
+    /// Returns the error message of glibc's `strerror(3)` for the codes of
+    /// the user-space `errno.h`.
+    ///
+    /// Kernel-internal codes, from 512 on, and codes redefined through
+    /// `AXERRNO_ERRNO_TABLE` return their [`as_str`](Self::as_str)
+    /// description, where glibc prints `\"Unknown error 512\"` and the like.
+    /// Codes without a named constant give `\"Unknown error\"`.
+    pub const fn strerror(&self) -> &'static str {{
+        match *self {{
+{3}            _ => \"Unknown error\",
+        }}
     }}
 
     /// Returns the error code value in `i32`.
     pub const fn code(self) -> i32 {{
-        self as i32
+        self.0
     }}
 }}
+
+#[cfg(feature = \"capi\")]
+impl LinuxError {{
+    /// Returns [`name`](Self::name) as a C string.
+    pub(crate) const fn c_name(self) -> Option<&'static core::ffi::CStr> {{
+        match self {{
+{6}            _ => None,
+        }}
+    }}
+
+    /// Returns [`as_str`](Self::as_str) as a C string.
+    pub(crate) const fn c_str(&self) -> &'static core::ffi::CStr {{
+        match *self {{
+{7}            _ => c\"Unknown error\",
+        }}
+    }}
+}}
+
+impl core::fmt::Debug for LinuxError {{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        match self.name() {{
+            Some(name) => f.write_str(name),
+            None => write!(f, \"LinuxError({{}})\", self.0),
+        }}
+    }}
+}}
+
+/// All error codes as `(number, name, message)` tuples, ordered by number.
+///
+/// The message is the one returned by [`LinuxError::as_str`].
+pub const LINUX_ERRNO_TABLE: &[(i32, &str, &str)] = &[
+{5}];
 "
     };
 }
 
+/// The environment variable pointing at a Linux uapi include tree, e.g.
+/// `linux/include/uapi` or `/usr/include`, to read `errno.h` from.
+const UAPI_ENV: &str = "AXERRNO_LINUX_UAPI";
+
+/// The environment variable pointing at a CSV file of extra error codes.
+const TABLE_ENV: &str = "AXERRNO_ERRNO_TABLE";
+
+/// Aliases defined by hand in `lib.rs` and `raw.rs`.
+const ALIASES: [&str; 3] = ["EWOULDBLOCK", "EDEADLOCK", "ENOTSUP"];
+
+/// The largest valid error code.
+const MAX_ERRNO: i32 = 4095;
+
+/// An error code parsed from a header.
+struct Errno {
+    name: String,
+    num: i32,
+    description: String,
+}
+
 fn main() {
     let out_dir = env::var_os("OUT_DIR").unwrap();
-    gen_linux_errno(&Path::new(&out_dir).join("linux_errno.rs")).unwrap();
+    let out_dir = Path::new(&out_dir);
+    gen_linux_errno(
+        &out_dir.join("linux_errno.rs"),
+        &out_dir.join("raw_errno.rs"),
+    )
+    .unwrap();
 }
 
-fn gen_linux_errno(dest_path: &Path) -> Result<()> {
-    let mut enum_define = Vec::new();
-    let mut try_from_i32 = Vec::new();
-    let mut detail_info = Vec::new();
+/// Returns the uapi headers: the ones under `$AXERRNO_LINUX_UAPI` if set,
+/// otherwise the vendored copy.
+fn uapi_headers() -> Vec<PathBuf> {
+    println!("cargo:rerun-if-env-changed={UAPI_ENV}");
+    let Some(dir) = env::var_os(UAPI_ENV) else {
+        return vec![PathBuf::from("src/errno.h")];
+    };
+    let dir = PathBuf::from(dir).join("asm-generic");
+    let headers = vec![dir.join("errno-base.h"), dir.join("errno.h")];
+    for header in &headers {
+        assert!(
+            header.is_file(),
+            "{UAPI_ENV} is set but {} does not exist",
+            header.display()
+        );
+    }
+    headers
+}
 
-    let file = File::open("src/errno.h")?;
-    for line in BufReader::new(file).lines().map_while(Result::ok) {
-        if line.starts_with("#define") {
-            let mut iter = line.split_whitespace();
-            if let Some(name) = iter.nth(1) {
-                if let Some(num) = iter.next() {
-                    let description = if let Some(pos) = line.find("/* ") {
-                        String::from(line[pos + 3..].trim_end_matches(" */"))
-                    } else {
-                        format!("Error number {num}")
-                    };
-                    writeln!(enum_define, "    /// {description}\n    {name} = {num},")?;
-                    writeln!(try_from_i32, "            {num} => Ok({name}),")?;
-                    writeln!(detail_info, "            {name} => \"{description}\",")?;
-                }
-            }
+/// Parses the `#define E... <number> /* description */` lines of a header.
+fn parse_header(path: &Path, errnos: &mut Vec<Errno>) -> Result<()> {
+    println!("cargo:rerun-if-changed={}", path.display());
+    for line in BufReader::new(File::open(path)?)
+        .lines()
+        .map_while(Result::ok)
+    {
+        if !line.starts_with("#define") {
+            continue;
+        }
+        let mut iter = line.split_whitespace();
+        let (Some(name), Some(num)) = (iter.nth(1), iter.next()) else {
+            continue;
+        };
+        // Skip aliases such as `#define EWOULDBLOCK EAGAIN`.
+        let Ok(num) = num.parse() else {
+            continue;
+        };
+        if errnos.iter().any(|e| e.name == name) {
+            continue;
         }
+        let description = match line.find("/* ") {
+            Some(pos) => String::from(line[pos + 3..].trim_end_matches(" */").trim()),
+            None => format!("Error number {num}"),
+        };
+        errnos.push(Errno {
+            name: name.to_string(),
+            num,
+            description,
+        });
     }
+    Ok(())
+}
+
+/// Applies the `name,number,message` lines of `$AXERRNO_ERRNO_TABLE`.
+///
+/// Entries named after an existing code replace it, the others are appended.
+fn apply_custom_table(errnos: &mut Vec<Errno>, glibc: &mut HashMap<String, String>) -> Result<()> {
+    println!("cargo:rerun-if-env-changed={TABLE_ENV}");
+    let Some(path) = env::var_os(TABLE_ENV) else {
+        return Ok(());
+    };
+    let path = PathBuf::from(path);
+    println!("cargo:rerun-if-changed={}", path.display());
+    for (lineno, line) in fs::read_to_string(&path)?.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, ',').map(str::trim);
+        let (Some(name), Some(Ok(num)), Some(message)) =
+            (fields.next(), fields.next().map(str::parse), fields.next())
+        else {
+            panic!(
+                "{}:{}: expected `name,number,message`",
+                path.display(),
+                lineno + 1
+            );
+        };
+        assert!(
+            !ALIASES.contains(&name),
+            "{}:{}: {name} is an alias and cannot be redefined",
+            path.display(),
+            lineno + 1
+        );
+        let errno = Errno {
+            name: name.to_string(),
+            num,
+            description: message.trim_matches('"').to_string(),
+        };
+        // The glibc message no longer describes a redefined code.
+        glibc.remove(name);
+        match errnos.iter_mut().find(|e| e.name == name) {
+            Some(e) => *e = errno,
+            None => errnos.push(errno),
+        }
+    }
+
+    for (i, e) in errnos.iter().enumerate() {
+        assert!(
+            (1..=MAX_ERRNO).contains(&e.num),
+            "{TABLE_ENV}: {} is {}, not in 1..={MAX_ERRNO}",
+            e.name,
+            e.num
+        );
+        if let Some(dup) = errnos[..i].iter().find(|d| d.num == e.num) {
+            panic!(
+                "{TABLE_ENV}: {} and {} are both {}",
+                dup.name, e.name, e.num
+            );
+        }
+    }
+    Ok(())
+}
+
+fn gen_linux_errno(dest_path: &Path, raw_path: &Path) -> Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let mut errnos = Vec::new();
+    for header in uapi_headers() {
+        parse_header(&header, &mut errnos)?;
+    }
+    parse_header(Path::new("src/errno_kernel.h"), &mut errnos)?;
+
+    let mut glibc_strerror = load_glibc_strerror()?;
+    apply_custom_table(&mut errnos, &mut glibc_strerror)?;
+    let use_glibc = env::var_os("CARGO_FEATURE_GLIBC_STRERROR").is_some();
+
+    let mut consts = Vec::new();
+    let mut names = Vec::new();
+    let mut detail_info = Vec::new();
+    let mut glibc_info = Vec::new();
+    let mut c_names = Vec::new();
+    let mut c_info = Vec::new();
+    let mut table = Vec::new();
+    let mut raw = Vec::new();
+    let mut max_code = 0;
+    for Errno {
+        name,
+        num,
+        description,
+    } in &errnos
+    {
+        max_code = max_code.max(*num);
+        writeln!(
+            consts,
+            "    /// {description}\n    pub const {name}: Self = Self({num});"
+        )?;
+        writeln!(names, "            Self::{name} => Some({name:?}),")?;
+        writeln!(raw, "/// {description}\npub const {name}: i32 = {num};")?;
+        let glibc = glibc_strerror.get(name).unwrap_or(description);
+        let message = if use_glibc { glibc } else { description };
+        writeln!(detail_info, "            Self::{name} => {message:?},")?;
+        writeln!(glibc_info, "            Self::{name} => {glibc:?},")?;
+        writeln!(c_names, "            Self::{name} => Some(c{name:?}),")?;
+        writeln!(c_info, "            Self::{name} => c{message:?},")?;
+        table.push((*num, format!("    ({num}, {name:?}, {message:?}),")));
+    }
+    table.sort_unstable_by_key(|(num, _)| *num);
+    let table = table.into_iter().map(|(_, line)| line + "\n");
 
     fs::write(
         dest_path,
         format!(
             template!(),
-            String::from_utf8_lossy(&enum_define),
-            String::from_utf8_lossy(&try_from_i32),
-            String::from_utf8_lossy(&detail_info)
+            String::from_utf8_lossy(&consts),
+            String::from_utf8_lossy(&names),
+            String::from_utf8_lossy(&detail_info),
+            String::from_utf8_lossy(&glibc_info),
+            max_code,
+            table.collect::<String>(),
+            String::from_utf8_lossy(&c_names),
+            String::from_utf8_lossy(&c_info),
+        ),
+    )?;
+    fs::write(
+        raw_path,
+        format!(
+            "// Generated by build.rs, DO NOT edit\n\n{}",
+            String::from_utf8_lossy(&raw)
         ),
     )?;
 
     Ok(())
 }
+
+fn load_glibc_strerror() -> Result<HashMap<String, String>> {
+    println!("cargo:rerun-if-changed=src/glibc_strerror.txt");
+    let mut messages = HashMap::new();
+    let file = File::open("src/glibc_strerror.txt")?;
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((name, message)) = line.split_once(char::is_whitespace) {
+            messages.insert(name.to_string(), message.trim().to_string());
+        }
+    }
+    Ok(messages)
+}
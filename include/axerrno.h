@@ -0,0 +1,25 @@
+/* C API of the axerrno crate, enabled by its `capi` feature. */
+
+#ifndef AXERRNO_H
+#define AXERRNO_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returns the static message of `errnum`, or "Unknown error". Never NULL. */
+const char *axerrno_strerror(int errnum);
+
+/* Returns the static name of `errnum`, e.g. "ENOENT", or NULL. */
+const char *axerrno_name(int errnum);
+
+/* Returns true if `errnum` is a named error number. */
+bool axerrno_is_valid(int errnum);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AXERRNO_H */
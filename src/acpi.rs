@@ -0,0 +1,212 @@
+//! Conversions between ACPICA status codes and [`AxError`].
+//!
+//! [`AcpiStatus`] mirrors the `ACPI_STATUS` values (`AE_*`) defined in
+//! ACPICA's `acexcep.h`, used by the ACPI table parser and AML interpreter.
+
+use core::fmt;
+
+use crate::{AxError, AxResult};
+
+macro_rules! acpi_status {
+    ($($name: ident = $value: literal => $err: ident,)*) => {
+        impl AcpiStatus {
+            $(
+                #[doc = concat!("`", stringify!($name), "`.")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the `AE_*` name of a known status code.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    Self::AE_OK => Some("AE_OK"),
+                    $(Self::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+
+            fn ax_error(self) -> AxError {
+                use AxError::*;
+                match self {
+                    $(Self::$name => $err,)*
+                    _ => match self.0 & Self::CLASS_MASK {
+                        Self::CLASS_TABLE | Self::CLASS_AML => InvalidData,
+                        Self::CLASS_PROGRAMMER => InvalidInput,
+                        _ => Io,
+                    },
+                }
+            }
+        }
+    };
+}
+
+/// An ACPICA status code (`ACPI_STATUS`).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{acpi::AcpiStatus, AxError};
+/// #
+/// assert_eq!(AcpiStatus::AE_NOT_FOUND.into_result(), Err(AxError::NotFound));
+/// assert_eq!(AcpiStatus::AE_OK.into_result(), Ok(()));
+/// assert_eq!(AcpiStatus::from(AxError::NoMemory), AcpiStatus::AE_NO_MEMORY);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AcpiStatus(pub u32);
+
+impl AcpiStatus {
+    /// Success.
+    pub const AE_OK: Self = Self(0);
+
+    const CLASS_MASK: u32 = 0xf000;
+    const CLASS_PROGRAMMER: u32 = 0x1000;
+    const CLASS_TABLE: u32 = 0x2000;
+    const CLASS_AML: u32 = 0x3000;
+
+    /// Returns `true` if the status is [`AE_OK`](Self::AE_OK).
+    pub const fn is_ok(self) -> bool {
+        self.0 == Self::AE_OK.0
+    }
+
+    /// Returns `true` if the status is not [`AE_OK`](Self::AE_OK).
+    pub const fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Converts the status into an [`AxResult`].
+    ///
+    /// Unknown table and AML exceptions become
+    /// [`InvalidData`](AxError::InvalidData), unknown programmer exceptions
+    /// [`InvalidInput`](AxError::InvalidInput), and all other unknown codes
+    /// [`Io`](AxError::Io).
+    pub fn into_result(self) -> AxResult {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(self.ax_error())
+        }
+    }
+}
+
+acpi_status! {
+    // Environmental exceptions.
+    AE_ERROR = 0x0001 => Io,
+    AE_NO_ACPI_TABLES = 0x0002 => NotFound,
+    AE_NO_NAMESPACE = 0x0003 => NotFound,
+    AE_NO_MEMORY = 0x0004 => NoMemory,
+    AE_NOT_FOUND = 0x0005 => NotFound,
+    AE_NOT_EXIST = 0x0006 => NotFound,
+    AE_ALREADY_EXISTS = 0x0007 => AlreadyExists,
+    AE_TYPE = 0x0008 => InvalidInput,
+    AE_NULL_OBJECT = 0x0009 => InvalidInput,
+    AE_NULL_ENTRY = 0x000a => InvalidInput,
+    AE_BUFFER_OVERFLOW = 0x000b => InvalidInput,
+    AE_STACK_OVERFLOW = 0x000c => BadState,
+    AE_STACK_UNDERFLOW = 0x000d => BadState,
+    AE_NOT_IMPLEMENTED = 0x000e => Unsupported,
+    AE_SUPPORT = 0x000f => NotSupported,
+    AE_LIMIT = 0x0010 => NoMemory,
+    AE_TIME = 0x0011 => Timeout,
+    AE_ACQUIRE_DEADLOCK = 0x0012 => BadState,
+    AE_RELEASE_DEADLOCK = 0x0013 => BadState,
+    AE_NOT_ACQUIRED = 0x0014 => BadState,
+    AE_ALREADY_ACQUIRED = 0x0015 => ResourceBusy,
+    AE_NO_HARDWARE_RESPONSE = 0x0016 => Timeout,
+    AE_NO_GLOBAL_LOCK = 0x0017 => Unsupported,
+    AE_ABORT_METHOD = 0x0018 => Interrupted,
+    AE_SAME_HANDLER = 0x0019 => AlreadyExists,
+    AE_NO_HANDLER = 0x001a => NotFound,
+    AE_OWNER_ID_LIMIT = 0x001b => NoMemory,
+    AE_NOT_CONFIGURED = 0x001c => BadState,
+    AE_ACCESS = 0x001d => PermissionDenied,
+    AE_IO_ERROR = 0x001e => Io,
+    AE_NUMERIC_OVERFLOW = 0x001f => InvalidData,
+    AE_HEX_OVERFLOW = 0x0020 => InvalidData,
+    AE_DECIMAL_OVERFLOW = 0x0021 => InvalidData,
+    AE_OCTAL_OVERFLOW = 0x0022 => InvalidData,
+    AE_END_OF_TABLE = 0x0023 => UnexpectedEof,
+
+    // Programmer exceptions.
+    AE_BAD_PARAMETER = 0x1001 => InvalidInput,
+    AE_BAD_CHARACTER = 0x1002 => InvalidInput,
+    AE_BAD_PATHNAME = 0x1003 => InvalidInput,
+    AE_BAD_DATA = 0x1004 => InvalidData,
+    AE_BAD_HEX_CONSTANT = 0x1005 => InvalidInput,
+    AE_BAD_OCTAL_CONSTANT = 0x1006 => InvalidInput,
+    AE_BAD_DECIMAL_CONSTANT = 0x1007 => InvalidInput,
+    AE_MISSING_ARGUMENTS = 0x1008 => InvalidInput,
+    AE_BAD_ADDRESS = 0x1009 => BadAddress,
+
+    // ACPI table exceptions.
+    AE_BAD_SIGNATURE = 0x2001 => InvalidData,
+    AE_BAD_HEADER = 0x2002 => InvalidData,
+    AE_BAD_CHECKSUM = 0x2003 => InvalidData,
+    AE_BAD_VALUE = 0x2004 => InvalidData,
+    AE_INVALID_TABLE_LENGTH = 0x2005 => InvalidData,
+}
+
+impl From<AxError> for AcpiStatus {
+    fn from(e: AxError) -> Self {
+        use AxError::*;
+        match e {
+            AlreadyExists => Self::AE_ALREADY_EXISTS,
+            BadAddress => Self::AE_BAD_ADDRESS,
+            InvalidData => Self::AE_BAD_DATA,
+            InvalidInput => Self::AE_BAD_PARAMETER,
+            Io => Self::AE_IO_ERROR,
+            NoMemory => Self::AE_NO_MEMORY,
+            NotFound => Self::AE_NOT_FOUND,
+            PermissionDenied => Self::AE_ACCESS,
+            ResourceBusy | Busy => Self::AE_ALREADY_ACQUIRED,
+            UnexpectedEof => Self::AE_END_OF_TABLE,
+            Unsupported => Self::AE_NOT_IMPLEMENTED,
+            NotSupported => Self::AE_SUPPORT,
+            Timeout => Self::AE_TIME,
+            Interrupted => Self::AE_ABORT_METHOD,
+            _ => Self::AE_ERROR,
+        }
+    }
+}
+
+impl From<AxResult> for AcpiStatus {
+    fn from(res: AxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::AE_OK)
+    }
+}
+
+impl fmt::Debug for AcpiStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "AcpiStatus({:#06x})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acpi_status() {
+        assert_eq!(
+            AcpiStatus::AE_BAD_CHECKSUM.into_result(),
+            Err(AxError::InvalidData)
+        );
+        assert_eq!(AcpiStatus(0x300b).into_result(), Err(AxError::InvalidData));
+        assert_eq!(AcpiStatus(0x10ff).into_result(), Err(AxError::InvalidInput));
+        assert_eq!(AcpiStatus::AE_TIME.name(), Some("AE_TIME"));
+        assert_eq!(AcpiStatus(0xffff).name(), None);
+        assert_eq!(AcpiStatus::from(Ok(())), AcpiStatus::AE_OK);
+
+        for e in [
+            AxError::NotFound,
+            AxError::Timeout,
+            AxError::BadAddress,
+            AxError::Unsupported,
+            AxError::NotSupported,
+        ] {
+            assert_eq!(AcpiStatus::from(e).into_result(), Err(e));
+        }
+    }
+}
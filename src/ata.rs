@@ -0,0 +1,197 @@
+//! Decoding of ATA status and error registers into [`AxError`].
+//!
+//! [`decode`] follows the classification of libata's `ata_eh_analyze_tf`,
+//! and the errors the Linux block layer returns for each class, so AHCI and
+//! legacy IDE drivers report media, transport and command errors apart
+//! instead of as plain [`Io`](AxError::Io) errors.
+
+use core::fmt;
+
+use crate::{AxError, AxResult};
+
+macro_rules! ata_bits {
+    ($ty: ident { $($(#[$doc: meta])* $name: ident = $value: literal,)* }) => {
+        impl $ty {
+            $(
+                $(#[$doc])*
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns `true` if all bits of `bits` are set.
+            pub const fn contains(self, bits: Self) -> bool {
+                self.0 & bits.0 == bits.0
+            }
+
+            /// Returns `true` if any bit of `bits` is set.
+            pub const fn intersects(self, bits: Self) -> bool {
+                self.0 & bits.0 != 0
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut sep = "";
+                write!(f, "{}(", stringify!($ty))?;
+                $(
+                    if self.contains(Self::$name) {
+                        write!(f, "{sep}{}", stringify!($name))?;
+                        sep = "|";
+                    }
+                )*
+                let unknown = self.0 & !(0 $(| $value)*);
+                if unknown != 0 {
+                    write!(f, "{sep}{unknown:#04x}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+/// The ATA Status register.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::ata::AtaStatus;
+/// #
+/// let status = AtaStatus(0x51);
+/// assert!(status.contains(AtaStatus::ERR));
+/// assert_eq!(format!("{status:?}"), "AtaStatus(ERR|DRDY|0x10)");
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AtaStatus(pub u8);
+
+ata_bits!(AtaStatus {
+    /// An error occurred; the Error register holds the cause.
+    ERR = 0x01,
+    /// The device is ready to transfer data.
+    DRQ = 0x08,
+    /// Device fault.
+    DF = 0x20,
+    /// The device is ready to accept commands.
+    DRDY = 0x40,
+    /// The device is busy; the other bits are not valid.
+    BSY = 0x80,
+});
+
+/// The ATA Error register, valid if [`AtaStatus::ERR`] is set.
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AtaErrorReg(pub u8);
+
+ata_bits!(AtaErrorReg {
+    /// Address mark not found.
+    AMNF = 0x01,
+    /// No media in a removable media device.
+    NM = 0x02,
+    /// The command was aborted, e.g. as unsupported.
+    ABRT = 0x04,
+    /// A media change was requested, e.g. with the eject button.
+    MCR = 0x08,
+    /// The requested address was not found.
+    IDNF = 0x10,
+    /// The media was changed.
+    MC = 0x20,
+    /// Uncorrectable data error.
+    UNC = 0x40,
+    /// Interface CRC error during an Ultra DMA transfer.
+    ICRC = 0x80,
+});
+
+/// Decodes the Status and Error registers after a command.
+///
+/// Returns `Ok(())` unless [`ERR`](AtaStatus::ERR) or
+/// [`DF`](AtaStatus::DF) is set. Errors are checked in the order of libata:
+///
+/// | Condition | Error |
+/// |---|---|
+/// | [`BSY`](AtaStatus::BSY) | [`Busy`](AxError::Busy), the registers are not valid |
+/// | [`DF`](AtaStatus::DF) | [`Io`](AxError::Io) |
+/// | [`ICRC`](AtaErrorReg::ICRC) | [`Io`](AxError::Io), a transport error worth retrying |
+/// | [`UNC`](AtaErrorReg::UNC), [`AMNF`](AtaErrorReg::AMNF) | [`NoData`](AxError::NoData), the `ENODATA` of medium errors in Linux |
+/// | [`IDNF`](AtaErrorReg::IDNF) | [`InvalidInput`](AxError::InvalidInput), the address is out of range |
+/// | [`NM`](AtaErrorReg::NM) | [`NoMedium`](AxError::NoMedium) |
+/// | [`MC`](AtaErrorReg::MC), [`MCR`](AtaErrorReg::MCR) | [`Again`](AxError::Again), after revalidating the media |
+/// | [`ABRT`](AtaErrorReg::ABRT) | [`Io`](AxError::Io), the `AC_ERR_DEV` the block layer reports as `EIO` |
+/// | none of the above | [`Io`](AxError::Io) |
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ata::{self, AtaErrorReg, AtaStatus}, AxError};
+/// #
+/// let status = AtaStatus(AtaStatus::DRDY.0 | AtaStatus::ERR.0);
+/// assert_eq!(ata::decode(status, AtaErrorReg::UNC), Err(AxError::NoData));
+/// assert_eq!(ata::decode(status, AtaErrorReg::ABRT), Err(AxError::Io));
+/// assert_eq!(ata::decode(AtaStatus::DRDY, AtaErrorReg(0xff)), Ok(()));
+/// ```
+pub fn decode(status: AtaStatus, error: AtaErrorReg) -> AxResult {
+    use AxError::*;
+    if status.contains(AtaStatus::BSY) {
+        return Err(Busy);
+    }
+    if !status.intersects(AtaStatus(AtaStatus::ERR.0 | AtaStatus::DF.0)) {
+        return Ok(());
+    }
+    // A device fault and an interface CRC error are both plain I/O errors,
+    // but take precedence over the media bits.
+    let err = if status.contains(AtaStatus::DF) || error.contains(AtaErrorReg::ICRC) {
+        Io
+    } else if error.intersects(AtaErrorReg(AtaErrorReg::UNC.0 | AtaErrorReg::AMNF.0)) {
+        NoData
+    } else if error.contains(AtaErrorReg::IDNF) {
+        InvalidInput
+    } else if error.contains(AtaErrorReg::NM) {
+        NoMedium
+    } else if error.intersects(AtaErrorReg(AtaErrorReg::MC.0 | AtaErrorReg::MCR.0)) {
+        Again
+    } else {
+        // An aborted command is a device error, not an unsupported one.
+        Io
+    };
+    Err(err)
+}
+
+/// Decodes the AHCI Port Task File Data register (`PxTFD`), which holds the
+/// Status register in bits 7:0 and the Error register in bits 15:8.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ata, AxError};
+/// #
+/// assert_eq!(ata::decode_tfd(0x0251), Err(AxError::NoMedium));
+/// assert_eq!(ata::decode_tfd(0x0451), Err(AxError::Io));
+/// assert_eq!(ata::decode_tfd(0x0050), Ok(()));
+/// ```
+pub fn decode_tfd(tfd: u32) -> AxResult {
+    decode(AtaStatus(tfd as u8), AtaErrorReg((tfd >> 8) as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let err = AtaStatus(0x51);
+        assert_eq!(
+            decode(AtaStatus(0xd1), AtaErrorReg::UNC),
+            Err(AxError::Busy)
+        );
+        assert_eq!(decode(AtaStatus(0x71), AtaErrorReg(0)), Err(AxError::Io));
+        assert_eq!(decode(AtaStatus(0x60), AtaErrorReg(0)), Err(AxError::Io));
+        // An interface CRC error is reported with ABRT.
+        assert_eq!(decode(err, AtaErrorReg(0x84)), Err(AxError::Io));
+        assert_eq!(decode(err, AtaErrorReg::AMNF), Err(AxError::NoData));
+        assert_eq!(decode(err, AtaErrorReg(0x14)), Err(AxError::InvalidInput));
+        assert_eq!(decode(err, AtaErrorReg::NM), Err(AxError::NoMedium));
+        assert_eq!(decode(err, AtaErrorReg::MCR), Err(AxError::Again));
+        assert_eq!(decode(err, AtaErrorReg::ABRT), Err(AxError::Io));
+        assert_eq!(decode(err, AtaErrorReg(0)), Err(AxError::Io));
+        assert_eq!(decode_tfd(0x4050), Ok(()));
+        assert_eq!(decode_tfd(0x4051), Err(AxError::NoData));
+    }
+}
@@ -0,0 +1,129 @@
+//! Looks up error codes during bring-up.
+//!
+//! ```text
+//! errno <number | name>...   describe errors by number, errno or AxError name
+//! errno --list               print the errno table
+//! errno --map                print the AxError to errno mapping
+//! ```
+
+use std::env;
+use std::fmt::Write;
+use std::process::ExitCode;
+
+use axerrno::{AxError, LinuxError, LINUX_ERRNO_TABLE};
+
+const USAGE: &str = "usage: errno <number | name>... | --list | --map";
+
+fn ax_errors() -> impl Iterator<Item = AxError> {
+    (1..).map_while(|code| AxError::try_from(code).ok())
+}
+
+/// Describes a [`LinuxError`] and the [`AxError`]s converting to it.
+fn describe_linux(err: LinuxError) -> String {
+    let mut out = format!("{} {err:?} {}", err.code(), err.as_str());
+    match AxError::try_from(err) {
+        Ok(ax) => write!(out, "\n  -> AxError::{ax:?}").unwrap(),
+        Err(_) => out.push_str("\n  -> no AxError"),
+    }
+    for ax in ax_errors().filter(|&ax| LinuxError::from(ax) == err) {
+        write!(out, "\n  <- AxError::{ax:?}").unwrap();
+    }
+    out
+}
+
+/// Describes an [`AxError`] and the [`LinuxError`] it converts to.
+fn describe_ax(err: AxError) -> String {
+    let linux = LinuxError::from(err);
+    format!(
+        "AxError::{err:?} ({}) {}\n  -> {} {linux:?}",
+        err as i32,
+        err.as_str(),
+        linux.code(),
+    )
+}
+
+fn lookup(arg: &str) -> Option<String> {
+    if let Ok(code) = arg.parse::<i32>() {
+        return LinuxError::new(code.checked_abs()?).map(describe_linux);
+    }
+    let name = arg.strip_prefix("AxError::").unwrap_or(arg);
+    if let Some(err) = ax_errors().find(|err| format!("{err:?}") == name) {
+        return Some(describe_ax(err));
+    }
+    LINUX_ERRNO_TABLE
+        .iter()
+        .find(|&&(_, errno, _)| errno.eq_ignore_ascii_case(name))
+        .and_then(|&(code, ..)| LinuxError::new(code))
+        .map(describe_linux)
+}
+
+fn map() -> String {
+    let mut out = String::new();
+    for err in ax_errors() {
+        let linux = LinuxError::from(err);
+        let lossless = AxError::try_from(linux) == Ok(err);
+        writeln!(
+            out,
+            "{:>3} {:<28} {} {:>4} {linux:?}",
+            err as i32,
+            format!("{err:?}"),
+            if lossless { "<->" } else { " ->" },
+            linux.code(),
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("-h" | "--help") => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(if args.is_empty() { 2 } else { 0 });
+        }
+        Some("--list") => {
+            let mut out = String::new();
+            axerrno::dump_table(&mut out).unwrap();
+            print!("{out}");
+            return ExitCode::SUCCESS;
+        }
+        Some("--map") => {
+            print!("{}", map());
+            return ExitCode::SUCCESS;
+        }
+        _ => {}
+    }
+    let mut status = ExitCode::SUCCESS;
+    for arg in &args {
+        match lookup(arg) {
+            Some(desc) => println!("{desc}"),
+            None => {
+                eprintln!("errno: unknown error `{arg}`");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let enoent = lookup("2").unwrap();
+        assert!(enoent.starts_with("2 ENOENT "));
+        assert!(enoent.contains("-> AxError::NotFound"));
+        assert_eq!(lookup("-2"), Some(enoent.clone()));
+        assert_eq!(lookup("enoent"), Some(enoent));
+        assert!(lookup("AxError::WouldBlock")
+            .unwrap()
+            .ends_with("-> 11 EAGAIN"));
+        assert!(lookup("ERESTARTSYS").unwrap().contains("no AxError"));
+        assert_eq!(lookup("0"), None);
+        assert_eq!(lookup("ENOPE"), None);
+        assert!(map().contains(" Io "));
+    }
+}
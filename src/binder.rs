@@ -0,0 +1,173 @@
+//! Conversions between Android Binder status codes and error codes.
+//!
+//! [`BinderStatus`] mirrors `status_t` of libutils, returned by Binder
+//! transactions and the services behind them. Most codes are negated Linux
+//! error numbers; the few that are not are offsets from
+//! [`UNKNOWN_ERROR`](BinderStatus::UNKNOWN_ERROR).
+
+use core::fmt;
+
+use crate::{AxError, AxResult, LinuxError};
+
+macro_rules! binder_status {
+    ($($name: ident = $value: expr;)*) => {
+        impl BinderStatus {
+            $(
+                #[doc = concat!("`", stringify!($name), "`.")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the name of a known status code.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+const fn errno(e: LinuxError) -> i32 {
+    -e.code()
+}
+
+/// An Android Binder `status_t`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{binder::BinderStatus, AxError, LinuxError};
+/// #
+/// assert_eq!(BinderStatus::NAME_NOT_FOUND.into_result(), Err(AxError::NotFound));
+/// assert_eq!(BinderStatus::DEAD_OBJECT.linux_errno(), Some(LinuxError::EPIPE));
+/// assert_eq!(BinderStatus::from(AxError::NoMemory), BinderStatus::NO_MEMORY);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct BinderStatus(pub i32);
+
+binder_status! {
+    OK = 0;
+    UNKNOWN_ERROR = i32::MIN;
+    NO_MEMORY = errno(LinuxError::ENOMEM);
+    INVALID_OPERATION = errno(LinuxError::ENOSYS);
+    BAD_VALUE = errno(LinuxError::EINVAL);
+    BAD_TYPE = i32::MIN + 1;
+    NAME_NOT_FOUND = errno(LinuxError::ENOENT);
+    PERMISSION_DENIED = errno(LinuxError::EPERM);
+    NO_INIT = errno(LinuxError::ENODEV);
+    ALREADY_EXISTS = errno(LinuxError::EEXIST);
+    DEAD_OBJECT = errno(LinuxError::EPIPE);
+    FAILED_TRANSACTION = i32::MIN + 2;
+    BAD_INDEX = errno(LinuxError::EOVERFLOW);
+    NOT_ENOUGH_DATA = errno(LinuxError::ENODATA);
+    WOULD_BLOCK = errno(LinuxError::EAGAIN);
+    TIMED_OUT = errno(LinuxError::ETIMEDOUT);
+    UNKNOWN_TRANSACTION = errno(LinuxError::EBADMSG);
+    FDS_NOT_ALLOWED = i32::MIN + 7;
+    UNEXPECTED_NULL = i32::MIN + 8;
+}
+
+impl BinderStatus {
+    /// An alias of [`OK`](Self::OK).
+    pub const NO_ERROR: Self = Self::OK;
+
+    /// Returns `true` if the status is [`OK`](Self::OK).
+    pub const fn is_ok(self) -> bool {
+        self.0 == Self::OK.0
+    }
+
+    /// Returns `true` if the status is not [`OK`](Self::OK).
+    pub const fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Returns the error code of the status, or `None` on success.
+    ///
+    /// Negated error numbers are returned as is, and the codes of
+    /// [`UNKNOWN_ERROR`](Self::UNKNOWN_ERROR) and above are approximated.
+    /// Unknown codes become [`EIO`](LinuxError::EIO).
+    pub fn linux_errno(self) -> Option<LinuxError> {
+        Some(match self {
+            Self::OK => return None,
+            Self::BAD_TYPE | Self::UNEXPECTED_NULL => LinuxError::EINVAL,
+            Self::FDS_NOT_ALLOWED => LinuxError::EPERM,
+            _ => self
+                .0
+                .checked_neg()
+                .and_then(LinuxError::new)
+                .unwrap_or(LinuxError::EIO),
+        })
+    }
+
+    /// Converts the status into an [`AxResult`].
+    ///
+    /// Error codes without an [`AxError`] become [`Io`](AxError::Io).
+    pub fn into_result(self) -> AxResult {
+        match self.linux_errno() {
+            None => Ok(()),
+            Some(e) => Err(AxError::try_from(e).unwrap_or(AxError::Io)),
+        }
+    }
+}
+
+impl From<LinuxError> for BinderStatus {
+    fn from(e: LinuxError) -> Self {
+        Self(errno(e))
+    }
+}
+
+impl From<AxError> for BinderStatus {
+    fn from(e: AxError) -> Self {
+        LinuxError::from(e).into()
+    }
+}
+
+impl From<AxResult> for BinderStatus {
+    fn from(res: AxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::OK)
+    }
+}
+
+impl fmt::Debug for BinderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "BinderStatus({})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binder_status() {
+        assert_eq!(BinderStatus::NO_ERROR.into_result(), Ok(()));
+        assert_eq!(BinderStatus::OK.linux_errno(), None);
+        assert_eq!(BinderStatus::UNKNOWN_ERROR.into_result(), Err(AxError::Io));
+        assert_eq!(
+            BinderStatus::FAILED_TRANSACTION.linux_errno(),
+            Some(LinuxError::EIO)
+        );
+        assert_eq!(
+            BinderStatus::UNEXPECTED_NULL.into_result(),
+            Err(AxError::InvalidInput)
+        );
+        assert_eq!(BinderStatus(-5000).linux_errno(), Some(LinuxError::EIO));
+        assert_eq!(BinderStatus(-5000).name(), None);
+        assert_eq!(BinderStatus(-2).name(), Some("NAME_NOT_FOUND"));
+
+        for e in [
+            AxError::NotFound,
+            AxError::PermissionDenied,
+            AxError::AlreadyExists,
+            AxError::WouldBlock,
+        ] {
+            assert_eq!(BinderStatus::from(e).into_result(), Err(e));
+        }
+        assert_eq!(BinderStatus::from(Ok(())), BinderStatus::OK);
+    }
+}
@@ -0,0 +1,235 @@
+//! Block layer status codes.
+//!
+//! [`BlockStatus`] mirrors Linux's `blk_status_t`: a one-byte status that
+//! storage drivers complete requests with, and that the block layer turns
+//! into an errno only when the request reaches the filesystem or user space.
+
+use core::fmt;
+
+use crate::{AxError, AxResult, LinuxError, LinuxResult};
+
+/// The completion status of a block request, as `blk_status_t`.
+///
+/// The values are those of the `BLK_STS_*` constants, and the conversions
+/// follow the `blk_errors` table of Linux: codes without a status of their
+/// own become [`IoError`](Self::IoError).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{block::BlockStatus, AxError, LinuxError};
+/// #
+/// assert_eq!(BlockStatus::from(LinuxError::ENODATA), BlockStatus::Medium);
+/// assert_eq!(BlockStatus::Medium.errno(), Some(LinuxError::ENODATA));
+/// assert_eq!(BlockStatus::from(AxError::Timeout), BlockStatus::TimedOut);
+/// assert_eq!(BlockStatus::from(LinuxError::EPERM), BlockStatus::IoError);
+/// assert_eq!(BlockStatus::Ok.into_result(), Ok(()));
+/// ```
+#[repr(u8)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BlockStatus {
+    /// The request completed successfully.
+    #[default]
+    Ok = 0,
+    /// The operation is not supported by the device (`EOPNOTSUPP`).
+    NotSupported = 1,
+    /// The request timed out (`ETIMEDOUT`).
+    TimedOut = 2,
+    /// The device is out of space, e.g. thinly provisioned storage
+    /// (`ENOSPC`).
+    NoSpace = 3,
+    /// The path to the device failed, e.g. with a link or CRC error, and
+    /// another path may succeed (`ENOLINK`).
+    Transport = 4,
+    /// The device rejected the request, and retrying on another path would
+    /// fail as well (`EREMOTEIO`).
+    Target = 5,
+    /// A persistent reservation held by another initiator conflicts with the
+    /// request (`EBADE`).
+    ReservationConflict = 6,
+    /// The medium could not be read or written (`ENODATA`).
+    Medium = 7,
+    /// The integrity metadata of the data did not verify (`EILSEQ`).
+    Protection = 8,
+    /// The driver ran out of a resource, e.g. memory (`ENOMEM`).
+    Resource = 9,
+    /// Any other I/O error (`EIO`).
+    IoError = 10,
+    /// The request would block and was submitted as non-blocking
+    /// (`EAGAIN`).
+    Again = 12,
+    /// The device ran out of a resource; the request is resubmitted when a
+    /// request of the device completes (`EBUSY`).
+    DeviceResource = 13,
+    /// A zoned device has too many open zones (`ETOOMANYREFS`).
+    ZoneOpenResource = 14,
+    /// A zoned device has too many active zones (`EOVERFLOW`).
+    ZoneActiveResource = 15,
+    /// The device is offline (`ENODEV`).
+    Offline = 16,
+    /// The command duration limit of the request was exceeded (`ETIME`).
+    DurationLimit = 17,
+    /// The request is invalid, e.g. misaligned (`EINVAL`).
+    Invalid = 19,
+}
+
+impl BlockStatus {
+    /// Returns `true` if the status is [`Ok`](Self::Ok).
+    pub const fn is_ok(self) -> bool {
+        matches!(self, BlockStatus::Ok)
+    }
+
+    /// Returns the error code of the status, or `None` for
+    /// [`Ok`](Self::Ok).
+    pub const fn errno(self) -> Option<LinuxError> {
+        use BlockStatus::*;
+        use LinuxError as E;
+        Some(match self {
+            Ok => return None,
+            NotSupported => E::EOPNOTSUPP,
+            TimedOut => E::ETIMEDOUT,
+            NoSpace => E::ENOSPC,
+            Transport => E::ENOLINK,
+            Target => E::EREMOTEIO,
+            ReservationConflict => E::EBADE,
+            Medium => E::ENODATA,
+            Protection => E::EILSEQ,
+            Resource => E::ENOMEM,
+            IoError => E::EIO,
+            Again => E::EAGAIN,
+            DeviceResource => E::EBUSY,
+            ZoneOpenResource => E::ETOOMANYREFS,
+            ZoneActiveResource => E::EOVERFLOW,
+            Offline => E::ENODEV,
+            DurationLimit => E::ETIME,
+            Invalid => E::EINVAL,
+        })
+    }
+
+    /// Converts the status into a [`LinuxResult`].
+    pub const fn into_result(self) -> LinuxResult {
+        match self.errno() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<LinuxError> for BlockStatus {
+    fn from(e: LinuxError) -> Self {
+        use BlockStatus::*;
+        use LinuxError as E;
+        match e {
+            E::EOPNOTSUPP => NotSupported,
+            E::ETIMEDOUT => TimedOut,
+            E::ENOSPC => NoSpace,
+            E::ENOLINK => Transport,
+            E::EREMOTEIO => Target,
+            E::EBADE => ReservationConflict,
+            E::ENODATA => Medium,
+            E::EILSEQ => Protection,
+            E::ENOMEM => Resource,
+            E::EAGAIN => Again,
+            E::EBUSY => DeviceResource,
+            E::ETOOMANYREFS => ZoneOpenResource,
+            E::EOVERFLOW => ZoneActiveResource,
+            E::ENODEV => Offline,
+            E::ETIME => DurationLimit,
+            E::EINVAL => Invalid,
+            _ => IoError,
+        }
+    }
+}
+
+/// [`Timeout`](AxError::Timeout) becomes [`TimedOut`](BlockStatus::TimedOut)
+/// rather than the [`DurationLimit`](BlockStatus::DurationLimit) of its
+/// `ETIME`; all other errors convert through their [`LinuxError`].
+impl From<AxError> for BlockStatus {
+    fn from(e: AxError) -> Self {
+        match e {
+            AxError::Timeout => BlockStatus::TimedOut,
+            e => LinuxError::from(e).into(),
+        }
+    }
+}
+
+impl From<AxResult> for BlockStatus {
+    fn from(res: AxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::Ok)
+    }
+}
+
+impl From<LinuxResult> for BlockStatus {
+    fn from(res: LinuxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::Ok)
+    }
+}
+
+impl TryFrom<u8> for BlockStatus {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use BlockStatus::*;
+        let status = match value {
+            0 => Ok,
+            1 => NotSupported,
+            2 => TimedOut,
+            3 => NoSpace,
+            4 => Transport,
+            5 => Target,
+            6 => ReservationConflict,
+            7 => Medium,
+            8 => Protection,
+            9 => Resource,
+            10 => IoError,
+            12 => Again,
+            13 => DeviceResource,
+            14 => ZoneOpenResource,
+            15 => ZoneActiveResource,
+            16 => Offline,
+            17 => DurationLimit,
+            19 => Invalid,
+            _ => return Err(value),
+        };
+        Result::Ok(status)
+    }
+}
+
+impl fmt::Display for BlockStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errno() {
+            Some(err) => fmt::Display::fmt(&err, f),
+            None => f.write_str("Success"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_status() {
+        for value in 0..=u8::MAX {
+            let Ok(status) = BlockStatus::try_from(value) else {
+                continue;
+            };
+            assert_eq!(status as u8, value);
+            match status.errno() {
+                Some(err) => assert_eq!(BlockStatus::from(err), status),
+                None => assert!(status.is_ok()),
+            }
+        }
+        assert_eq!(BlockStatus::try_from(11), Err(11));
+        assert_eq!(
+            BlockStatus::from(AxError::NoData).into_result(),
+            Err(LinuxError::ENODATA)
+        );
+        assert_eq!(BlockStatus::from(AxResult::Ok(())), BlockStatus::Ok);
+        assert_eq!(
+            BlockStatus::from(LinuxResult::Err(LinuxError::ENXIO)),
+            BlockStatus::IoError
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! C API to the error tables.
+//!
+//! The remaining C components (assembly stubs, the C allocator and the glue
+//! of vendor blobs) link against these functions instead of duplicating the
+//! tables. Their prototypes are in `include/axerrno.h`.
+
+use core::ffi::{c_char, c_int};
+use core::ptr;
+
+use crate::LinuxError;
+
+/// Returns the message of the error number `errnum`, like `strerror(3)`.
+///
+/// Never returns `NULL`; unknown numbers give `"Unknown error"`. The string
+/// is static and must not be freed.
+#[no_mangle]
+pub extern "C" fn axerrno_strerror(errnum: c_int) -> *const c_char {
+    match LinuxError::new(errnum) {
+        Some(err) => err.c_str().as_ptr(),
+        None => c"Unknown error".as_ptr(),
+    }
+}
+
+/// Returns the name of the error number `errnum`, e.g. `"ENOENT"`, or `NULL`
+/// if it has none.
+#[no_mangle]
+pub extern "C" fn axerrno_name(errnum: c_int) -> *const c_char {
+    match LinuxError::new(errnum).and_then(LinuxError::c_name) {
+        Some(name) => name.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Returns `true` if `errnum` is a named error number.
+#[no_mangle]
+pub extern "C" fn axerrno_is_valid(errnum: c_int) -> bool {
+    LinuxError::new(errnum).is_some_and(LinuxError::is_known)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::CStr;
+
+    use super::*;
+
+    #[test]
+    fn test_capi() {
+        let message = unsafe { CStr::from_ptr(axerrno_strerror(2)) };
+        assert_eq!(message.to_str(), Ok(LinuxError::ENOENT.as_str()));
+        let unknown = unsafe { CStr::from_ptr(axerrno_strerror(-1)) };
+        assert_eq!(unknown, c"Unknown error");
+
+        assert_eq!(unsafe { CStr::from_ptr(axerrno_name(11)) }, c"EAGAIN");
+        assert!(axerrno_name(4000).is_null());
+        assert!(axerrno_is_valid(1));
+        assert!(!axerrno_is_valid(0));
+        assert!(!axerrno_is_valid(4000));
+    }
+}
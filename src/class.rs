@@ -0,0 +1,244 @@
+//! Coarse classification of errors by domain.
+//!
+//! Metrics and memory pressure heuristics bucket errors with
+//! [`AxError::classify`] and [`LinuxError::classify`] instead of maintaining
+//! their own lists.
+
+use crate::{AxError, LinuxError};
+
+/// The domain an error belongs to.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ErrorClass {
+    /// Files, directories and storage.
+    Filesystem,
+    /// Sockets and network connectivity.
+    Network,
+    /// Memory allocation and addressing.
+    Memory,
+    /// Missing privileges.
+    Permission,
+    /// Contention or temporary unavailability; the operation may succeed
+    /// later.
+    Resource,
+    /// Malformed or invalid requests and data.
+    Protocol,
+    /// Unsupported operations, internal bugs and everything else.
+    Internal,
+}
+
+impl AxError {
+    /// Returns the domain of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{class::ErrorClass, AxError};
+    /// #
+    /// assert_eq!(AxError::NoMemory.classify(), ErrorClass::Memory);
+    /// assert_eq!(AxError::ConnectionReset.classify(), ErrorClass::Network);
+    /// ```
+    pub const fn classify(self) -> ErrorClass {
+        use AxError::*;
+        match self {
+            AlreadyExists
+            | DirectoryNotEmpty
+            | IsADirectory
+            | NotADirectory
+            | NotFound
+            | StorageFull
+            | FilesystemCorrupted
+            | ReadOnlyFilesystem
+            | Io
+            | WriteZero
+            | UnexpectedEof
+            | BadFileDescriptor
+            | NotABlockDevice
+            | CrossesDevices
+            | ExecutableFileBusy
+            | FileTooLarge
+            | NotSeekable
+            | TooManyLinks
+            | NameTooLong
+            | FilesystemLoop
+            | StaleNetworkFileHandle
+            | QuotaExceeded => ErrorClass::Filesystem,
+            AddrInUse
+            | ConnectionRefused
+            | ConnectionReset
+            | NotConnected
+            | BrokenPipe
+            | NotASocket
+            | DestinationAddressRequired
+            | ProtocolNotSupported
+            | SocketTypeNotSupported
+            | AddressFamilyNotSupported
+            | AddrNotAvailable
+            | NetworkDown
+            | NetworkUnreachable
+            | ConnectionAborted
+            | AlreadyConnected
+            | EndpointShutdown
+            | ConnectionTimedOut
+            | HostDown
+            | HostUnreachable
+            | AlreadyInProgress
+            | InProgress
+            | LinkSevered
+            | RfKill => ErrorClass::Network,
+            NoMemory | BadAddress | HardwareMemoryError => ErrorClass::Memory,
+            PermissionDenied | OperationNotPermitted => ErrorClass::Permission,
+            ResourceBusy | Busy | WouldBlock | Again | Timeout | Interrupted
+            | FileTableOverflow | TooManyOpenFiles | Deadlock | NoLocksAvailable
+            | NoBufferSpace | NoStreamResources => ErrorClass::Resource,
+            InvalidData
+            | InvalidInput
+            | InvalidExecutable
+            | BadSharedLibrary
+            | ArgumentListTooLong
+            | NotATty
+            | OutOfRange
+            | ArgumentOutOfDomain
+            | ProtocolError
+            | BadMessage
+            | Overflow
+            | IllegalByteSequence
+            | MessageTooLong
+            | WrongProtocolType
+            | ProtocolOptionUnavailable => ErrorClass::Protocol,
+            BadState
+            | Unsupported
+            | NotSupported
+            | NoSuchProcess
+            | NoSuchDeviceOrAddress
+            | NoChildProcess
+            | NoSuchDevice
+            | NoMessage
+            | IdentifierRemoved
+            | NoData
+            | NoMedium
+            | Canceled
+            | ProbeDeferred
+            | NotAStream
+            | OwnerDead
+            | NotRecoverable
+            | CommunicationError
+            | RemoteIo => ErrorClass::Internal,
+        }
+    }
+}
+
+impl LinuxError {
+    /// Returns the domain of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{class::ErrorClass, LinuxError};
+    /// #
+    /// assert_eq!(LinuxError::EROFS.classify(), ErrorClass::Filesystem);
+    /// assert_eq!(LinuxError::EHOSTUNREACH.classify(), ErrorClass::Network);
+    /// ```
+    pub const fn classify(self) -> ErrorClass {
+        use LinuxError as E;
+        match self {
+            E::ENOENT
+            | E::EEXIST
+            | E::ENOTDIR
+            | E::EISDIR
+            | E::ENOTEMPTY
+            | E::ENOSPC
+            | E::EROFS
+            | E::EUCLEAN
+            | E::EIO
+            | E::EBADF
+            | E::EXDEV
+            | E::EMLINK
+            | E::ENAMETOOLONG
+            | E::ELOOP
+            | E::EFBIG
+            | E::ETXTBSY
+            | E::ESPIPE
+            | E::EDQUOT
+            | E::ESTALE
+            | E::ENOTBLK => ErrorClass::Filesystem,
+            E::EADDRINUSE
+            | E::EADDRNOTAVAIL
+            | E::ECONNREFUSED
+            | E::ECONNRESET
+            | E::ECONNABORTED
+            | E::ENOTCONN
+            | E::EISCONN
+            | E::ENETDOWN
+            | E::ENETUNREACH
+            | E::ENETRESET
+            | E::EHOSTDOWN
+            | E::EHOSTUNREACH
+            | E::ENOTSOCK
+            | E::EDESTADDRREQ
+            | E::EPIPE
+            | E::ESHUTDOWN
+            | E::ETIMEDOUT
+            | E::EAFNOSUPPORT
+            | E::EPFNOSUPPORT
+            | E::EPROTONOSUPPORT
+            | E::ESOCKTNOSUPPORT
+            | E::EALREADY
+            | E::EINPROGRESS
+            | E::ENOLINK
+            | E::ERFKILL => ErrorClass::Network,
+            E::ENOMEM | E::EFAULT | E::EHWPOISON => ErrorClass::Memory,
+            E::EPERM | E::EACCES => ErrorClass::Permission,
+            E::EAGAIN
+            | E::EBUSY
+            | E::EINTR
+            | E::ETIME
+            | E::EMFILE
+            | E::ENFILE
+            | E::ENOBUFS
+            | E::EDEADLK
+            | E::ENOLCK
+            | E::EUSERS
+            | E::ENOSR => ErrorClass::Resource,
+            E::EINVAL
+            | E::EPROTO
+            | E::EBADMSG
+            | E::EMSGSIZE
+            | E::EPROTOTYPE
+            | E::ENOPROTOOPT
+            | E::EOVERFLOW
+            | E::ERANGE
+            | E::EDOM
+            | E::EILSEQ
+            | E::E2BIG
+            | E::ENOEXEC
+            | E::ELIBBAD
+            | E::ENOTTY => ErrorClass::Protocol,
+            _ => ErrorClass::Internal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            AxError::FilesystemCorrupted.classify(),
+            ErrorClass::Filesystem
+        );
+        assert_eq!(AxError::Unsupported.classify(), ErrorClass::Internal);
+        assert_eq!(LinuxError::ENOSYS.classify(), ErrorClass::Internal);
+        assert_eq!(LinuxError::ERESTARTSYS.classify(), ErrorClass::Internal);
+
+        // Both types agree wherever the conversion round-trips.
+        for code in 1..=core::mem::variant_count::<AxError>() as i32 {
+            let err = AxError::try_from(code).unwrap();
+            let linux = LinuxError::from(err);
+            if AxError::try_from(linux) == Ok(err) {
+                assert_eq!(err.classify(), linux.classify());
+            }
+        }
+    }
+}
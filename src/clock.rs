@@ -0,0 +1,27 @@
+//! The time source used to rate limit and timestamp errors.
+//!
+//! This crate has no notion of time by itself. The OS installs its monotonic
+//! clock once with [`set_clock`].
+
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::time::Duration;
+
+static CLOCK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs the function returning the current monotonic time.
+///
+/// Replaces the previously installed clock, if any.
+pub fn set_clock(now: fn() -> Duration) {
+    CLOCK.store(now as *mut (), Ordering::Release);
+}
+
+/// Returns the current monotonic time, or `None` if no clock is installed.
+pub fn now() -> Option<Duration> {
+    let clock = CLOCK.load(Ordering::Acquire);
+    if clock.is_null() {
+        return None;
+    }
+    // SAFETY: only `fn() -> Duration` pointers are stored in `CLOCK`.
+    let now = unsafe { core::mem::transmute::<*mut (), fn() -> Duration>(clock) };
+    Some(now())
+}
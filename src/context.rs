@@ -0,0 +1,82 @@
+//! Task context prefixed to the warnings of the error macros.
+//!
+//! The OS registers a [`ContextProvider`] with [`set_context_provider`], and
+//! [`ax_err!`] and its variants then prefix their warnings with the current
+//! task, e.g. `[pid 7 tid 9 syscall 56] fs::open: [AxError::NotFound]`.
+//! The provider is not consulted with the `compact-log` and `deferred-log`
+//! features.
+//!
+//! [`ax_err!`]: crate::ax_err
+
+use core::fmt;
+
+use crate::global::GlobalRef;
+use crate::AxResult;
+
+/// The task an error was raised in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TaskContext {
+    /// The process ID.
+    pub pid: Option<u32>,
+    /// The thread ID.
+    pub tid: Option<u32>,
+    /// The number of the syscall being handled.
+    pub syscall: Option<usize>,
+}
+
+impl fmt::Display for TaskContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sep = "";
+        if let Some(pid) = self.pid {
+            write!(f, "pid {pid}")?;
+            sep = " ";
+        }
+        if let Some(tid) = self.tid {
+            write!(f, "{sep}tid {tid}")?;
+            sep = " ";
+        }
+        if let Some(syscall) = self.syscall {
+            write!(f, "{sep}syscall {syscall}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Provides the current [`TaskContext`].
+///
+/// It is called on the error path, possibly in interrupt context, so it must
+/// not block.
+pub trait ContextProvider: Sync {
+    /// Returns the context of the current task, or `None` outside of a task,
+    /// e.g. during boot.
+    fn context(&self) -> Option<TaskContext>;
+}
+
+static PROVIDER: GlobalRef<dyn ContextProvider> = GlobalRef::new();
+
+/// Registers the context provider.
+///
+/// Returns [`AxError::AlreadyExists`](crate::AxError::AlreadyExists) if a
+/// provider is already registered.
+pub fn set_context_provider(provider: &'static dyn ContextProvider) -> AxResult {
+    PROVIDER.set(provider)
+}
+
+/// Returns the current context, if a provider is registered and returns one.
+pub fn current() -> Option<TaskContext> {
+    PROVIDER.get()?.context()
+}
+
+/// Displays the `[context] ` prefix of a warning, or nothing if there is no
+/// context.
+#[doc(hidden)]
+pub struct __Prefix;
+
+impl fmt::Display for __Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match current() {
+            Some(ctx) => write!(f, "[{ctx}] "),
+            None => Ok(()),
+        }
+    }
+}
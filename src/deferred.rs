@@ -0,0 +1,168 @@
+//! Deferred logging of errors raised in interrupt context.
+//!
+//! Logging an error takes the console lock, which may already be held by the
+//! interrupted task. [`record`] instead stores the error and its location in a
+//! fixed-size lock-free ring: it performs no formatting, no allocation and no
+//! logging. The pending records are logged later from task context with
+//! [`flush`], or consumed with [`drain`].
+//!
+//! With the `deferred-log` feature, [`ax_err!`] and the other error macros
+//! record into this ring instead of logging. Their messages are discarded.
+//!
+//! [`ax_err!`]: crate::ax_err
+
+use core::panic::Location;
+use core::sync::atomic::{AtomicI32, AtomicPtr, AtomicUsize, Ordering};
+
+use crate::AxError;
+
+/// The number of records the ring holds before new ones are dropped.
+pub const DEFERRED_CAPACITY: usize = 64;
+
+/// An error recorded by [`record`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DeferredError {
+    /// The error.
+    pub err: AxError,
+    /// The source location the error was raised at.
+    pub location: &'static Location<'static>,
+}
+
+struct Slot {
+    /// `pos` when the slot is free for the write at `pos`, `pos + 1` once the
+    /// record at `pos` is ready to be read.
+    seq: AtomicUsize,
+    code: AtomicI32,
+    location: AtomicPtr<Location<'static>>,
+}
+
+const fn slots() -> [Slot; DEFERRED_CAPACITY] {
+    let mut slots = [const {
+        Slot {
+            seq: AtomicUsize::new(0),
+            code: AtomicI32::new(0),
+            location: AtomicPtr::new(core::ptr::null_mut()),
+        }
+    }; DEFERRED_CAPACITY];
+    let mut i = 0;
+    while i < DEFERRED_CAPACITY {
+        slots[i].seq = AtomicUsize::new(i);
+        i += 1;
+    }
+    slots
+}
+
+static SLOTS: [Slot; DEFERRED_CAPACITY] = slots();
+static HEAD: AtomicUsize = AtomicUsize::new(0);
+static TAIL: AtomicUsize = AtomicUsize::new(0);
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+/// Records an error raised at `location`.
+///
+/// Never blocks: if the ring is full, the record is dropped and counted.
+#[cold]
+pub fn record(err: AxError, location: &'static Location<'static>) {
+    let mut pos = TAIL.load(Ordering::Relaxed);
+    let slot = loop {
+        let slot = &SLOTS[pos % DEFERRED_CAPACITY];
+        let seq = slot.seq.load(Ordering::Acquire);
+        if seq == pos {
+            let claimed =
+                TAIL.compare_exchange_weak(pos, pos + 1, Ordering::Relaxed, Ordering::Relaxed);
+            match claimed {
+                Ok(_) => break slot,
+                Err(cur) => pos = cur,
+            }
+        } else if seq < pos {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+            return;
+        } else {
+            pos = TAIL.load(Ordering::Relaxed);
+        }
+    };
+    slot.code.store(err.code(), Ordering::Relaxed);
+    slot.location
+        .store(location as *const _ as *mut _, Ordering::Relaxed);
+    slot.seq.store(pos + 1, Ordering::Release);
+}
+
+/// Removes the pending records in order and passes them to `f`.
+///
+/// Returns the number of records dropped because the ring was full since the
+/// last call.
+pub fn drain(mut f: impl FnMut(DeferredError)) -> usize {
+    let mut pos = HEAD.load(Ordering::Relaxed);
+    loop {
+        let slot = &SLOTS[pos % DEFERRED_CAPACITY];
+        if slot.seq.load(Ordering::Acquire) != pos + 1 {
+            break;
+        }
+        if let Err(cur) =
+            HEAD.compare_exchange_weak(pos, pos + 1, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            pos = cur;
+            continue;
+        }
+        let code = slot.code.load(Ordering::Relaxed);
+        let location = slot.location.load(Ordering::Relaxed);
+        slot.seq.store(pos + DEFERRED_CAPACITY, Ordering::Release);
+        pos += 1;
+
+        // SAFETY: only valid codes and `&'static Location`s are recorded.
+        f(DeferredError {
+            err: AxError::try_from(code).unwrap_or(AxError::BadState),
+            location: unsafe { &*location },
+        });
+    }
+    DROPPED.swap(0, Ordering::Relaxed)
+}
+
+/// Logs the pending records under the [`LOG_TARGET`](crate::LOG_TARGET)
+/// target.
+///
+/// Must be called from task context.
+#[cfg(feature = "log")]
+pub fn flush() {
+    let dropped = drain(|e| {
+        log::warn!(
+            target: crate::LOG_TARGET,
+            "{}: [AxError::{:?}] (deferred)",
+            e.location,
+            e.err
+        );
+    });
+    if dropped > 0 {
+        log::warn!(
+            target: crate::LOG_TARGET,
+            "{dropped} deferred errors dropped"
+        );
+    }
+}
+
+// Other tests record into the same ring with `deferred-log`.
+#[cfg(all(test, not(feature = "deferred-log")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_drain() {
+        let here = Location::caller();
+        for _ in 0..DEFERRED_CAPACITY + 3 {
+            record(AxError::Io, here);
+        }
+        record(AxError::Busy, here);
+
+        let mut count = 0;
+        let dropped = drain(|e| {
+            assert_eq!(e.err, AxError::Io);
+            assert_eq!(e.location, here);
+            count += 1;
+        });
+        assert_eq!((count, dropped), (DEFERRED_CAPACITY, 4));
+
+        record(AxError::Busy, here);
+        let mut last = None;
+        assert_eq!(drain(|e| last = Some(e.err)), 0);
+        assert_eq!(last, Some(AxError::Busy));
+    }
+}
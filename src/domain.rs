@@ -0,0 +1,225 @@
+//! Out-of-tree error domains with private code ranges.
+//!
+//! Drivers that need richer internal codes than the POSIX set can register an
+//! [`ErrorDomain`]. Each registered domain owns [`DOMAIN_CODE_COUNT`] codes
+//! starting at [`CUSTOM_CODE_BASE`], so its errors can be stored losslessly as
+//! an `i32` inside the kernel without colliding with [`LinuxError`] values.
+//! At the user boundary, every error of a domain is translated to the domain's
+//! [`user_errno`](ErrorDomain::user_errno).
+
+use core::fmt;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{AxError, AxResult, LinuxError};
+
+/// The first code of the range reserved for custom error domains.
+pub const CUSTOM_CODE_BASE: i32 = 0x1000;
+
+/// The number of codes owned by each registered domain.
+pub const DOMAIN_CODE_COUNT: i32 = 0x1000;
+
+/// The maximum number of domains that can be registered.
+pub const MAX_DOMAINS: usize = 16;
+
+static DOMAINS: [AtomicPtr<ErrorDomain>; MAX_DOMAINS] =
+    [const { AtomicPtr::new(ptr::null_mut()) }; MAX_DOMAINS];
+
+/// Description of an out-of-tree error domain.
+///
+/// # Examples
+///
+/// ```
+/// use axerrno::domain::{register_domain, ErrorDomain};
+/// use axerrno::LinuxError;
+///
+/// static NVME: ErrorDomain = ErrorDomain {
+///     name: "nvme",
+///     user_errno: LinuxError::EIO,
+///     messages: &["Invalid opcode", "Invalid field in command"],
+/// };
+///
+/// let nvme = register_domain(&NVME).unwrap();
+/// let err = nvme.error(1);
+/// assert_eq!(err.as_str(), "Invalid field in command");
+/// assert_eq!(LinuxError::from(err), LinuxError::EIO);
+/// ```
+#[derive(Debug)]
+pub struct ErrorDomain {
+    /// The name of the domain, e.g. the driver name.
+    pub name: &'static str,
+    /// The error code reported to user space for errors of this domain.
+    pub user_errno: LinuxError,
+    /// Descriptions of the domain codes, indexed by code.
+    pub messages: &'static [&'static str],
+}
+
+/// Registers an error domain and returns its identifier.
+///
+/// Registering the same domain twice returns the same identifier. Returns
+/// [`AxError::AlreadyExists`] if another domain with the same name is
+/// registered, or [`AxError::NoMemory`] if all [`MAX_DOMAINS`] slots are taken.
+pub fn register_domain(domain: &'static ErrorDomain) -> AxResult<DomainId> {
+    let new = domain as *const ErrorDomain as *mut ErrorDomain;
+    for (id, slot) in DOMAINS.iter().enumerate() {
+        let cur = match slot.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => return Ok(DomainId(id as u8)),
+            Err(cur) => cur,
+        };
+        if cur == new {
+            return Ok(DomainId(id as u8));
+        }
+        // SAFETY: non-null slots always hold a `&'static ErrorDomain`.
+        if unsafe { &*cur }.name == domain.name {
+            return Err(AxError::AlreadyExists);
+        }
+    }
+    Err(AxError::NoMemory)
+}
+
+/// Identifier of a registered [`ErrorDomain`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DomainId(u8);
+
+impl DomainId {
+    /// Returns the registered domain.
+    pub fn domain(self) -> &'static ErrorDomain {
+        // SAFETY: a `DomainId` is only handed out for a registered slot, and
+        // registered slots are never cleared.
+        unsafe { &*DOMAINS[self.0 as usize].load(Ordering::Acquire) }
+    }
+
+    /// Returns the first raw code owned by this domain.
+    pub const fn base(self) -> i32 {
+        CUSTOM_CODE_BASE + self.0 as i32 * DOMAIN_CODE_COUNT
+    }
+
+    /// Constructs an error of this domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not less than [`DOMAIN_CODE_COUNT`].
+    pub const fn error(self, code: u16) -> DomainError {
+        assert!(
+            (code as i32) < DOMAIN_CODE_COUNT,
+            "domain code out of range"
+        );
+        DomainError { id: self, code }
+    }
+}
+
+/// An error of a registered [`ErrorDomain`].
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DomainError {
+    id: DomainId,
+    code: u16,
+}
+
+impl DomainError {
+    /// Decodes a raw code produced by [`DomainError::raw`].
+    ///
+    /// Returns `None` if the value is outside the custom range or its domain
+    /// is not registered.
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        let offset = raw.checked_sub(CUSTOM_CODE_BASE)?;
+        if offset < 0 {
+            return None;
+        }
+        let id = (offset / DOMAIN_CODE_COUNT) as usize;
+        if id >= MAX_DOMAINS || DOMAINS[id].load(Ordering::Acquire).is_null() {
+            return None;
+        }
+        Some(Self {
+            id: DomainId(id as u8),
+            code: (offset % DOMAIN_CODE_COUNT) as u16,
+        })
+    }
+
+    /// Returns the raw code, which is unique across all registered domains.
+    pub const fn raw(self) -> i32 {
+        self.id.base() + self.code as i32
+    }
+
+    /// Returns the identifier of the domain this error belongs to.
+    pub const fn domain_id(self) -> DomainId {
+        self.id
+    }
+
+    /// Returns the domain this error belongs to.
+    pub fn domain(self) -> &'static ErrorDomain {
+        self.id.domain()
+    }
+
+    /// Returns the domain specific code.
+    pub const fn code(self) -> u16 {
+        self.code
+    }
+
+    /// Returns the error description, or the domain name if the domain does
+    /// not describe this code.
+    pub fn as_str(self) -> &'static str {
+        let domain = self.domain();
+        domain
+            .messages
+            .get(self.code as usize)
+            .copied()
+            .unwrap_or(domain.name)
+    }
+}
+
+impl From<DomainError> for LinuxError {
+    fn from(e: DomainError) -> Self {
+        e.domain().user_errno
+    }
+}
+
+impl fmt::Debug for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DomainError({}:{})", self.domain().name, self.code)
+    }
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.domain().name, self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST: ErrorDomain = ErrorDomain {
+        name: "test",
+        user_errno: LinuxError::EINVAL,
+        messages: &["First", "Second"],
+    };
+
+    static DUPLICATE: ErrorDomain = ErrorDomain {
+        name: "test",
+        user_errno: LinuxError::EIO,
+        messages: &[],
+    };
+
+    #[test]
+    fn test_domain_error() {
+        let id = register_domain(&TEST).unwrap();
+        assert_eq!(register_domain(&TEST), Ok(id));
+        assert_eq!(register_domain(&DUPLICATE), Err(AxError::AlreadyExists));
+
+        let err = id.error(1);
+        assert!(err.raw() >= CUSTOM_CODE_BASE);
+        assert_eq!(DomainError::from_raw(err.raw()), Some(err));
+        assert_eq!(err.as_str(), "Second");
+        assert_eq!(id.error(7).as_str(), "test");
+        assert_eq!(LinuxError::from(err), LinuxError::EINVAL);
+
+        assert_eq!(DomainError::from_raw(LinuxError::EIO.code()), None);
+        assert_eq!(DomainError::from_raw(id.base() + DOMAIN_CODE_COUNT), None);
+    }
+}
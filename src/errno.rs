@@ -0,0 +1,142 @@
+//! Raw error numbers.
+
+use core::ffi::c_int;
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+/// A raw error number.
+///
+/// Unlike [`LinuxError`], it holds any value, including codes of hardware,
+/// protocols or other ABIs outside of `1..=MAX_ERRNO`, so they can be passed
+/// along without being mangled or rejected.
+///
+/// # ABI
+///
+/// `Errno` is `#[repr(transparent)]` over an `i32`, which is the same type as
+/// `c_int` on every supported target, so it can be used in place of `int` in
+/// `extern "C"` signatures and `#[repr(C)]` structs shared with C. The value
+/// is the positive error number, as stored in `errno`; negated codes returned
+/// by syscalls must be negated before being wrapped.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, Errno, LinuxError};
+/// #
+/// let known = Errno::from(LinuxError::ENOENT);
+/// assert_eq!(known.linux(), Some(LinuxError::ENOENT));
+/// assert_eq!(known.ax(), Some(AxError::NotFound));
+///
+/// let foreign = Errno(0x8000_0001_u32 as i32);
+/// assert_eq!(foreign.linux(), None);
+/// assert_eq!(foreign.code() as u32, 0x8000_0001);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+pub struct Errno(pub i32);
+
+/// The name of [`Errno`] in FFI signatures, standing for a C `int` error code.
+pub type ErrnoCode = Errno;
+
+impl Errno {
+    /// Returns the error number.
+    pub const fn code(self) -> i32 {
+        self.0
+    }
+
+    /// Returns the [`LinuxError`] with this number, if it is in
+    /// `1..=MAX_ERRNO`.
+    pub fn linux(self) -> Option<LinuxError> {
+        LinuxError::try_from(self.0).ok()
+    }
+
+    /// Returns the [`AxError`] the [`LinuxError`] with this number converts
+    /// to, if there is one.
+    pub fn ax(self) -> Option<AxError> {
+        AxError::try_from(self.linux()?).ok()
+    }
+}
+
+impl From<LinuxError> for Errno {
+    fn from(e: LinuxError) -> Self {
+        Self(e.code())
+    }
+}
+
+impl From<AxError> for Errno {
+    fn from(e: AxError) -> Self {
+        LinuxError::from(e).into()
+    }
+}
+
+impl From<c_int> for Errno {
+    fn from(code: c_int) -> Self {
+        Self(code)
+    }
+}
+
+impl From<Errno> for c_int {
+    fn from(e: Errno) -> Self {
+        e.0
+    }
+}
+
+impl TryFrom<Errno> for AxError {
+    type Error = Errno;
+
+    fn try_from(e: Errno) -> Result<Self, Self::Error> {
+        e.ax().ok_or(e)
+    }
+}
+
+impl TryFrom<Errno> for LinuxError {
+    type Error = Errno;
+
+    fn try_from(e: Errno) -> Result<Self, Self::Error> {
+        e.linux().ok_or(e)
+    }
+}
+
+impl fmt::Debug for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.linux() {
+            Some(e) if e.is_known() => write!(f, "{e:?}"),
+            _ => write!(f, "Errno({})", self.0),
+        }
+    }
+}
+
+impl fmt::Display for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.linux() {
+            Some(e) if e.is_known() => write!(f, "{e}"),
+            _ => write!(f, "Unknown error {}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errno() {
+        assert_eq!(Errno::from(AxError::WouldBlock), Errno(11));
+        assert_eq!(LinuxError::try_from(Errno(11)), Ok(LinuxError::EAGAIN));
+        assert_eq!(LinuxError::try_from(Errno(-1)), Err(Errno(-1)));
+        assert_eq!(Errno(LinuxError::ECHRNG.code()).ax(), None);
+        assert_eq!(Errno(0x2000).ax(), None);
+        assert_eq!(AxError::try_from(Errno(2)), Ok(AxError::NotFound));
+        assert_eq!(c_int::from(Errno::from(5)), 5);
+    }
+}
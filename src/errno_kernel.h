@@ -0,0 +1,34 @@
+/* SPDX-License-Identifier: GPL-2.0 */
+/* Copy from include/linux/errno.h */
+#ifndef _LINUX_ERRNO_H
+#define _LINUX_ERRNO_H
+
+/*
+ * These should never be seen by user programs.  To return one of ERESTART*
+ * codes, signal_pending() MUST be set.  Note that ptrace can observe these
+ * at syscall exit tracing, but they will never be left for the debugged user
+ * process to see.
+ */
+#define ERESTARTSYS     512 /* Restart syscall unless the handler lacks SA_RESTART */
+#define ERESTARTNOINTR  513 /* Always restart syscall */
+#define ERESTARTNOHAND  514 /* restart if no handler.. */
+#define ENOIOCTLCMD     515 /* No ioctl command */
+#define ERESTART_RESTARTBLOCK 516 /* restart by calling sys_restart_syscall */
+#define EPROBE_DEFER    517 /* Driver requests probe retry */
+#define EOPENSTALE      518 /* open found a stale dentry */
+#define ENOPARAM        519 /* Parameter not supported */
+
+/* Defined for the NFSv3 protocol */
+#define EBADHANDLE      521 /* Illegal NFS file handle */
+#define ENOTSYNC        522 /* Update synchronization mismatch */
+#define EBADCOOKIE      523 /* Cookie is stale */
+#define ENOTSUPP        524 /* Operation is not supported */
+#define ETOOSMALL       525 /* Buffer or request is too small */
+#define ESERVERFAULT    526 /* An untranslatable error occurred */
+#define EBADTYPE        527 /* Type not supported by server */
+#define EJUKEBOX        528 /* Request initiated, but will not complete before timeout */
+#define EIOCBQUEUED     529 /* iocb queued, will get completion event */
+#define ERECALLCONFLICT 530 /* conflict with recalled state */
+#define ENOGRACE        531 /* NFS file lock reclaim refused */
+
+#endif
@@ -0,0 +1,120 @@
+//! Process exit codes for hosted tools.
+//!
+//! `AxResult<()>` and `LinuxResult<()>` can already be returned from `main`,
+//! exiting with status `1` on failure. [`Exit`] additionally chooses the exit
+//! status from the error with an [`ExitPolicy`].
+
+use std::eprintln;
+use std::fmt::Debug;
+use std::process::{ExitCode, Termination};
+
+use crate::sysexits::SysExit;
+use crate::{AxError, LinuxError};
+
+/// How an error is turned into a process exit status.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ExitPolicy {
+    /// Exit with status `1`, like a `Result` returned from `main`.
+    #[default]
+    Failure,
+    /// Exit with the error number, truncated to 8 bits.
+    Errno,
+    /// Exit with the [`SysExit`] code of the error.
+    SysExits,
+}
+
+impl ExitPolicy {
+    /// Returns the exit status of `err` under this policy.
+    ///
+    /// Under [`SysExits`](Self::SysExits), codes without an [`AxError`]
+    /// exit with [`SysExit::Software`].
+    pub fn exit_status(self, err: impl Into<LinuxError>) -> u8 {
+        let err = err.into();
+        match self {
+            ExitPolicy::Failure => 1,
+            // Codes above 255 would wrap to unrelated statuses, or to 0.
+            ExitPolicy::Errno => u8::try_from(err.code()).unwrap_or(u8::MAX),
+            ExitPolicy::SysExits => AxError::try_from(err)
+                .map_or(SysExit::Software, SysExit::from)
+                .code(),
+        }
+    }
+
+    /// Returns the [`ExitCode`] of `res` under this policy.
+    pub fn exit_code<E: Into<LinuxError>>(self, res: Result<(), E>) -> ExitCode {
+        match res {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => self.exit_status(err).into(),
+        }
+    }
+}
+
+/// The result of `main`, exiting with the status chosen by an
+/// [`ExitPolicy`].
+///
+/// Like a `Result` returned from `main`, an error is printed to the standard
+/// error with its `Debug` representation.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use axerrno::{exit::{Exit, ExitPolicy}, AxError, AxResult};
+/// #
+/// fn run() -> AxResult {
+///     Err(AxError::NotFound)
+/// }
+///
+/// fn main() -> Exit {
+///     // Exit with status 2.
+///     Exit::new(run(), ExitPolicy::Errno)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Exit<E = AxError> {
+    result: Result<(), E>,
+    policy: ExitPolicy,
+}
+
+impl<E> Exit<E> {
+    /// Wraps `result` to exit with the status chosen by `policy`.
+    pub const fn new(result: Result<(), E>, policy: ExitPolicy) -> Self {
+        Self { result, policy }
+    }
+}
+
+impl<E> From<Result<(), E>> for Exit<E> {
+    fn from(result: Result<(), E>) -> Self {
+        Self::new(result, ExitPolicy::default())
+    }
+}
+
+impl<E: Into<LinuxError> + Debug> Termination for Exit<E> {
+    fn report(self) -> ExitCode {
+        if let Err(err) = &self.result {
+            eprintln!("Error: {err:?}");
+        }
+        self.policy.exit_code(self.result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_policy() {
+        assert_eq!(ExitPolicy::Failure.exit_status(AxError::NotFound), 1);
+        assert_eq!(ExitPolicy::Errno.exit_status(AxError::NotFound), 2);
+        assert_eq!(ExitPolicy::Errno.exit_status(LinuxError::ERESTARTSYS), 255);
+        assert_eq!(ExitPolicy::SysExits.exit_status(AxError::NotFound), 66);
+        assert_eq!(ExitPolicy::SysExits.exit_status(LinuxError::ECHRNG), 70);
+
+        let ok: Result<(), LinuxError> = Ok(());
+        assert_eq!(ExitPolicy::Errno.exit_code(ok), ExitCode::SUCCESS);
+        assert_eq!(
+            Exit::new(Err(AxError::PermissionDenied), ExitPolicy::SysExits).report(),
+            ExitCode::from(77)
+        );
+        assert_eq!(Exit::from(Err(LinuxError::EIO)).report(), ExitCode::FAILURE);
+    }
+}
@@ -0,0 +1,151 @@
+//! Machine-readable exports of the error tables.
+//!
+//! Tracers, dashboards and test generators can read the codes, names and
+//! messages of [`LinuxError`] and [`AxError`] from these exports instead of
+//! keeping their own copies.
+
+use std::fmt::{self, Write};
+use std::string::String;
+
+use crate::{AxError, LinuxError, LINUX_ERRNO_TABLE};
+
+/// Returns the [`AxError`]s in the order of their codes.
+fn ax_errors() -> impl Iterator<Item = AxError> {
+    (1..=core::mem::variant_count::<AxError>() as i32)
+        .filter_map(|code| AxError::try_from(code).ok())
+}
+
+/// Exports both tables as a JSON object.
+///
+/// The `errno` array holds the `number`, `name` and `message` of every
+/// [`LinuxError`] in [`LINUX_ERRNO_TABLE`]. The `ax_error` array holds the
+/// same for every [`AxError`], plus the `errno` it converts to.
+///
+/// # Examples
+///
+/// ```
+/// let json = axerrno::export::export_json();
+/// assert!(json.contains(r#"{"number":2,"name":"ENOENT","message":"No such file or directory"}"#));
+/// assert!(json.contains(r#"{"number":15,"name":"NotFound","message":"Entity not found","errno":2}"#));
+/// ```
+pub fn export_json() -> String {
+    let mut out = String::new();
+    write_json(&mut out).unwrap();
+    out
+}
+
+/// Exports both tables as CSV, with a header line.
+///
+/// The columns are `table` (`errno` or `AxError`), `number`, `name`,
+/// `errno` and `message`. The `errno` of a [`LinuxError`] is its number.
+///
+/// # Examples
+///
+/// ```
+/// let csv = axerrno::export::export_csv();
+/// assert!(csv.starts_with("table,number,name,errno,message\n"));
+/// assert!(csv.contains("\nerrno,2,ENOENT,2,\"No such file or directory\"\n"));
+/// assert!(csv.contains("\nAxError,15,NotFound,2,\"Entity not found\"\n"));
+/// ```
+pub fn export_csv() -> String {
+    let mut out = String::new();
+    write_csv(&mut out).unwrap();
+    out
+}
+
+fn write_json(w: &mut String) -> fmt::Result {
+    w.push_str("{\"errno\":[");
+    for (i, &(num, name, message)) in LINUX_ERRNO_TABLE.iter().enumerate() {
+        if i > 0 {
+            w.push(',');
+        }
+        write!(w, "{{\"number\":{num},\"name\":")?;
+        write_json_str(w, name);
+        w.push_str(",\"message\":");
+        write_json_str(w, message);
+        w.push('}');
+    }
+    w.push_str("],\"ax_error\":[");
+    for (i, err) in ax_errors().enumerate() {
+        if i > 0 {
+            w.push(',');
+        }
+        write!(
+            w,
+            "{{\"number\":{},\"name\":\"{err:?}\",\"message\":",
+            err as i32
+        )?;
+        write_json_str(w, err.as_str());
+        write!(w, ",\"errno\":{}}}", LinuxError::from(err).code())?;
+    }
+    w.push_str("]}");
+    Ok(())
+}
+
+fn write_json_str(w: &mut String, s: &str) {
+    w.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => w.push_str("\\\""),
+            '\\' => w.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(w, "\\u{:04x}", c as u32);
+            }
+            c => w.push(c),
+        }
+    }
+    w.push('"');
+}
+
+fn write_csv(w: &mut String) -> fmt::Result {
+    w.push_str("table,number,name,errno,message\n");
+    for &(num, name, message) in LINUX_ERRNO_TABLE {
+        write!(w, "errno,{num},{name},{num},")?;
+        write_csv_str(w, message);
+    }
+    for err in ax_errors() {
+        let errno = LinuxError::from(err).code();
+        write!(w, "AxError,{},{err:?},{errno},", err as i32)?;
+        write_csv_str(w, err.as_str());
+    }
+    Ok(())
+}
+
+fn write_csv_str(w: &mut String, s: &str) {
+    w.push('"');
+    w.push_str(&s.replace('"', "\"\""));
+    w.push_str("\"\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        let json = export_json();
+        assert!(json.starts_with("{\"errno\":[{\"number\":1,\"name\":\"EPERM\""));
+        assert!(json.ends_with("}]}"));
+        assert!(json
+            .contains(r#"{"number":15,"name":"NotFound","message":"Entity not found","errno":2}"#));
+        assert_eq!(
+            json.matches("\"name\"").count(),
+            LINUX_ERRNO_TABLE.len() + core::mem::variant_count::<AxError>()
+        );
+
+        let csv = export_csv();
+        assert_eq!(
+            csv.lines().count(),
+            1 + LINUX_ERRNO_TABLE.len() + core::mem::variant_count::<AxError>()
+        );
+        assert!(csv.contains("\nerrno,1,EPERM,1,\"Operation not permitted\"\n"));
+        assert!(csv.contains("\nAxError,15,NotFound,2,\"Entity not found\"\n"));
+
+        let mut escaped = String::new();
+        write_json_str(&mut escaped, "a\"b\\\n");
+        assert_eq!(escaped, r#""a\"b\\\u000a""#);
+        let mut quoted = String::new();
+        write_csv_str(&mut quoted, "say \"hi\"");
+        assert_eq!(quoted, "\"say \"\"hi\"\"\"\n");
+    }
+}
@@ -0,0 +1,422 @@
+#[cfg(feature = "log")]
+use core::fmt;
+#[cfg(feature = "log")]
+use core::panic::Location;
+use core::task::Poll;
+
+#[cfg(feature = "log")]
+use log::{Level, Metadata, Record};
+
+use crate::{AxError, AxResult, AxResultAt, LinuxError, LinuxResult};
+
+/// Extension methods for [`AxResult`].
+pub trait AxResultExt<T> {
+    /// Turns a [`WouldBlock`](AxError::WouldBlock) or [`Again`](AxError::Again)
+    /// error into `Ok(None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, AxResult, AxResultExt};
+    /// #
+    /// let res: AxResult<usize> = Err(AxError::WouldBlock);
+    /// assert_eq!(res.or_would_block(), Ok(None));
+    /// ```
+    fn or_would_block(self) -> AxResult<Option<T>>;
+
+    /// Converts the error into a [`LinuxError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, AxResult, AxResultExt, LinuxError};
+    /// #
+    /// let res: AxResult<usize> = Err(AxError::NotFound);
+    /// assert_eq!(res.into_linux(), Err(LinuxError::ENOENT));
+    /// ```
+    fn into_linux(self) -> LinuxResult<T>;
+
+    /// Turns a [`WouldBlock`](AxError::WouldBlock), [`Again`](AxError::Again)
+    /// or [`InProgress`](AxError::InProgress) error into [`Poll::Pending`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::task::Poll;
+    /// # use axerrno::{AxError, AxResult, AxResultExt};
+    /// #
+    /// let res: AxResult<usize> = Err(AxError::WouldBlock);
+    /// assert_eq!(res.into_poll(), Poll::Pending);
+    /// ```
+    fn into_poll(self) -> Poll<AxResult<T>>;
+
+    /// Attaches the location of the caller to the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, AxResult, AxResultExt};
+    /// #
+    /// let res: AxResult<usize> = Err(AxError::Io);
+    /// let err = res.at().unwrap_err();
+    /// assert_eq!(err.location.file(), file!());
+    /// ```
+    #[track_caller]
+    fn at(self) -> AxResultAt<T>;
+
+    /// Logs the error at `level` with the location of the caller, and returns
+    /// the result unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, AxResult, AxResultExt};
+    /// #
+    /// let res: AxResult<usize> = Err(AxError::Io);
+    /// // Print "<file>:<line>:<column>: [AxError::Io]" at debug level.
+    /// let res = res.log_err(log::Level::Debug);
+    /// assert_eq!(res, Err(AxError::Io));
+    /// ```
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn log_err(self, level: Level) -> Self;
+
+    /// Logs the error as a warning with the location of the caller, and
+    /// returns the result unchanged.
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn tap_err_warn(self) -> Self;
+}
+
+impl<T> AxResultExt<T> for AxResult<T> {
+    fn or_would_block(self) -> AxResult<Option<T>> {
+        match self {
+            Ok(v) => Ok(Some(v)),
+            Err(AxError::WouldBlock | AxError::Again) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn into_linux(self) -> LinuxResult<T> {
+        self.map_err(LinuxError::from)
+    }
+
+    fn into_poll(self) -> Poll<AxResult<T>> {
+        match self {
+            Err(AxError::WouldBlock | AxError::Again | AxError::InProgress) => Poll::Pending,
+            res => Poll::Ready(res),
+        }
+    }
+
+    #[track_caller]
+    fn at(self) -> AxResultAt<T> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.at()),
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn log_err(self, level: Level) -> Self {
+        match &self {
+            Err(e) if log_enabled(level) => {
+                log_at(level, Location::caller(), format_args!("[AxError::{e:?}]"));
+            }
+            _ => {}
+        }
+        self
+    }
+
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn tap_err_warn(self) -> Self {
+        self.log_err(Level::Warn)
+    }
+}
+
+/// Checks the static and dynamic maximum levels like [`log::log!`], so that
+/// the `max_level_*` features of `log` compile the call to [`log_at`] out.
+#[cfg(feature = "log")]
+#[inline(always)]
+fn log_enabled(level: Level) -> bool {
+    level <= log::STATIC_MAX_LEVEL && level <= log::max_level()
+}
+
+#[cfg(feature = "log")]
+#[cold]
+#[inline(never)]
+fn log_at(level: Level, location: &'static Location<'static>, msg: fmt::Arguments) {
+    let metadata = Metadata::builder()
+        .level(level)
+        .target(crate::LOG_TARGET)
+        .build();
+    let logger = log::logger();
+    if logger.enabled(&metadata) {
+        logger.log(
+            &Record::builder()
+                .metadata(metadata)
+                .file_static(Some(location.file()))
+                .line(Some(location.line()))
+                .args(format_args!("{location}: {msg}"))
+                .build(),
+        );
+    }
+}
+
+/// Extension methods for [`LinuxResult`].
+pub trait LinuxResultExt<T> {
+    /// Turns an [`EAGAIN`](LinuxError::EAGAIN) error into `Ok(None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{LinuxError, LinuxResult, LinuxResultExt};
+    /// #
+    /// let res: LinuxResult<usize> = Err(LinuxError::EAGAIN);
+    /// assert_eq!(res.or_would_block(), Ok(None));
+    /// ```
+    fn or_would_block(self) -> LinuxResult<Option<T>>;
+
+    /// Converts the error into an [`AxError`].
+    ///
+    /// Returns the outer `Err` with the original code if no [`AxError`] maps
+    /// to it, see `TryFrom<LinuxError> for AxError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, LinuxError, LinuxResult, LinuxResultExt};
+    /// #
+    /// let res: LinuxResult<usize> = Err(LinuxError::ENOENT);
+    /// assert_eq!(res.into_ax(), Ok(Err(AxError::NotFound)));
+    /// let res: LinuxResult<usize> = Err(LinuxError::ECHRNG);
+    /// assert_eq!(res.into_ax(), Err(LinuxError::ECHRNG));
+    /// ```
+    fn into_ax(self) -> Result<AxResult<T>, LinuxError>;
+
+    /// Converts the error into an [`AxError`], turning codes no [`AxError`]
+    /// maps to into [`Io`](AxError::Io).
+    fn into_ax_lossy(self) -> AxResult<T>;
+
+    /// Turns an [`EAGAIN`](LinuxError::EAGAIN) or
+    /// [`EINPROGRESS`](LinuxError::EINPROGRESS) error into [`Poll::Pending`].
+    fn into_poll(self) -> Poll<LinuxResult<T>>;
+
+    /// Logs the error at `level` with the location of the caller, and returns
+    /// the result unchanged.
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn log_err(self, level: Level) -> Self;
+
+    /// Logs the error as a warning with the location of the caller, and
+    /// returns the result unchanged.
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn tap_err_warn(self) -> Self;
+}
+
+impl<T> LinuxResultExt<T> for LinuxResult<T> {
+    fn or_would_block(self) -> LinuxResult<Option<T>> {
+        match self {
+            Ok(v) => Ok(Some(v)),
+            Err(LinuxError::EAGAIN) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn into_ax(self) -> Result<AxResult<T>, LinuxError> {
+        match self {
+            Ok(v) => Ok(Ok(v)),
+            Err(e) => AxError::try_from(e).map(Err),
+        }
+    }
+
+    fn into_ax_lossy(self) -> AxResult<T> {
+        self.map_err(|e| AxError::try_from(e).unwrap_or(AxError::Io))
+    }
+
+    fn into_poll(self) -> Poll<LinuxResult<T>> {
+        match self {
+            Err(LinuxError::EAGAIN | LinuxError::EINPROGRESS) => Poll::Pending,
+            res => Poll::Ready(res),
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn log_err(self, level: Level) -> Self {
+        match &self {
+            Err(e) if log_enabled(level) => {
+                log_at(
+                    level,
+                    Location::caller(),
+                    format_args!("[LinuxError::{e:?}]"),
+                );
+            }
+            _ => {}
+        }
+        self
+    }
+
+    #[cfg(feature = "log")]
+    #[track_caller]
+    fn tap_err_warn(self) -> Self {
+        self.log_err(Level::Warn)
+    }
+}
+
+/// Extension methods for [`Poll`]s of [`AxResult`] and [`LinuxResult`], the
+/// reverse of `into_poll`.
+///
+/// # Examples
+///
+/// ```
+/// # use core::task::Poll;
+/// # use axerrno::{AxError, AxResult, LinuxError, LinuxResult, PollExt};
+/// #
+/// let poll: Poll<AxResult<usize>> = Poll::Pending;
+/// assert_eq!(poll.into_result(), Err(AxError::WouldBlock));
+/// let poll: Poll<LinuxResult<usize>> = Poll::Ready(Ok(4));
+/// assert_eq!(poll.into_result(), Ok(4));
+/// ```
+pub trait PollExt {
+    /// The result type.
+    type Output;
+
+    /// Turns [`Poll::Pending`] into a [`WouldBlock`](AxError::WouldBlock) or
+    /// [`EAGAIN`](LinuxError::EAGAIN) error.
+    fn into_result(self) -> Self::Output;
+}
+
+impl<T> PollExt for Poll<AxResult<T>> {
+    type Output = AxResult<T>;
+
+    fn into_result(self) -> AxResult<T> {
+        match self {
+            Poll::Ready(res) => res,
+            Poll::Pending => Err(AxError::WouldBlock),
+        }
+    }
+}
+
+impl<T> PollExt for Poll<LinuxResult<T>> {
+    type Output = LinuxResult<T>;
+
+    fn into_result(self) -> LinuxResult<T> {
+        match self {
+            Poll::Ready(res) => res,
+            Poll::Pending => Err(LinuxError::EAGAIN),
+        }
+    }
+}
+
+/// Extension methods converting [`Option`] into [`AxResult`].
+///
+/// See also [`ok_or_ax!`](crate::ok_or_ax) which additionally prints a warning.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, OptionExt};
+/// #
+/// let inode: Option<u64> = None;
+/// assert_eq!(inode.ok_or_not_found(), Err(AxError::NotFound));
+/// assert_eq!(Some(2).ok_or_ax(AxError::BadState), Ok(2));
+/// ```
+pub trait OptionExt<T> {
+    /// Transforms `Some(v)` into `Ok(v)` and `None` into `Err(err)`.
+    fn ok_or_ax(self, err: AxError) -> AxResult<T>;
+
+    /// Transforms `None` into [`NotFound`](AxError::NotFound).
+    fn ok_or_not_found(self) -> AxResult<T>;
+
+    /// Transforms `None` into [`InvalidInput`](AxError::InvalidInput).
+    fn ok_or_invalid(self) -> AxResult<T>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_ax(self, err: AxError) -> AxResult<T> {
+        self.ok_or(err)
+    }
+
+    fn ok_or_not_found(self) -> AxResult<T> {
+        self.ok_or(AxError::NotFound)
+    }
+
+    fn ok_or_invalid(self) -> AxResult<T> {
+        self.ok_or(AxError::InvalidInput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_or_would_block() {
+        assert_eq!(AxResult::Ok(1).or_would_block(), Ok(Some(1)));
+        assert_eq!(
+            AxResult::<()>::Err(AxError::Again).or_would_block(),
+            Ok(None)
+        );
+        assert_eq!(
+            AxResult::<()>::Err(AxError::Io).or_would_block(),
+            Err(AxError::Io)
+        );
+        assert_eq!(
+            LinuxResult::<()>::Err(LinuxError::EINTR).or_would_block(),
+            Err(LinuxError::EINTR)
+        );
+    }
+
+    #[test]
+    fn test_result_conversion() {
+        assert_eq!(AxResult::Ok(1).into_linux(), Ok(1));
+        assert_eq!(
+            AxResult::<()>::Err(AxError::Busy).into_linux(),
+            Err(LinuxError::EBUSY)
+        );
+        assert_eq!(LinuxResult::Ok(1).into_ax(), Ok(Ok(1)));
+        assert_eq!(
+            LinuxResult::<()>::Err(LinuxError::EAGAIN).into_ax_lossy(),
+            Err(AxError::WouldBlock)
+        );
+        assert_eq!(
+            LinuxResult::<()>::Err(LinuxError::ECHRNG).into_ax_lossy(),
+            Err(AxError::Io)
+        );
+    }
+
+    #[test]
+    fn test_option_ext() {
+        assert_eq!(Some(1).ok_or_not_found(), Ok(1));
+        assert_eq!(None::<u8>.ok_or_invalid(), Err(AxError::InvalidInput));
+        assert_eq!(None::<u8>.ok_or_ax(AxError::Busy), Err(AxError::Busy));
+        assert_eq!(
+            crate::ok_or_ax!(None::<u8>, NotFound, "no such key"),
+            Err(AxError::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_poll() {
+        assert_eq!(
+            AxResult::<()>::Err(AxError::InProgress).into_poll(),
+            Poll::Pending
+        );
+        assert_eq!(AxResult::Ok(1).into_poll(), Poll::Ready(Ok(1)));
+        assert_eq!(
+            LinuxResult::<()>::Err(LinuxError::EIO).into_poll(),
+            Poll::Ready(Err(LinuxError::EIO))
+        );
+        assert_eq!(
+            LinuxResult::<()>::Err(LinuxError::EINPROGRESS).into_poll(),
+            Poll::Pending
+        );
+        assert_eq!(
+            Poll::<LinuxResult<()>>::Pending.into_result(),
+            Err(LinuxError::EAGAIN)
+        );
+    }
+}
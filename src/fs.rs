@@ -0,0 +1,96 @@
+//! Fine-grained filesystem failure kinds.
+//!
+//! Filesystem drivers use [`FsError`] internally to tell corruption apart
+//! from other failures, and convert it into an [`AxError`] or [`LinuxError`]
+//! at the VFS boundary.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+/// A filesystem failure.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{fs::FsError, AxError, LinuxError};
+/// #
+/// let err = FsError::CorruptedMetadata;
+/// assert!(err.is_corruption());
+/// assert_eq!(AxError::from(err), AxError::FilesystemCorrupted);
+/// assert_eq!(LinuxError::from(err), LinuxError::EUCLEAN);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FsError {
+    /// On-disk metadata is inconsistent, e.g. an inode points outside the
+    /// volume.
+    CorruptedMetadata,
+    /// A block failed its checksum. Reported as an I/O error, like a failed
+    /// read of the block.
+    BadChecksum,
+    /// The journal must be replayed before the filesystem can be written.
+    JournalReplayNeeded,
+    /// The filesystem uses a feature the driver cannot write, so it can only
+    /// be used read-only.
+    UnsupportedFeature,
+}
+
+impl FsError {
+    /// Returns the error description.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CorruptedMetadata => "Corrupted filesystem metadata",
+            Self::BadChecksum => "Bad block checksum",
+            Self::JournalReplayNeeded => "Journal replay needed",
+            Self::UnsupportedFeature => "Unsupported filesystem feature",
+        }
+    }
+
+    /// Returns `true` if the error indicates on-disk corruption.
+    pub const fn is_corruption(self) -> bool {
+        matches!(self, Self::CorruptedMetadata | Self::BadChecksum)
+    }
+}
+
+impl From<FsError> for AxError {
+    fn from(e: FsError) -> Self {
+        match e {
+            FsError::CorruptedMetadata => AxError::FilesystemCorrupted,
+            FsError::BadChecksum => AxError::Io,
+            FsError::JournalReplayNeeded | FsError::UnsupportedFeature => {
+                AxError::ReadOnlyFilesystem
+            }
+        }
+    }
+}
+
+impl From<FsError> for LinuxError {
+    fn from(e: FsError) -> Self {
+        AxError::from(e).into()
+    }
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fs_error() {
+        assert_eq!(LinuxError::from(FsError::BadChecksum), LinuxError::EIO);
+        assert_eq!(
+            LinuxError::from(FsError::JournalReplayNeeded),
+            LinuxError::EROFS
+        );
+        assert!(!FsError::UnsupportedFeature.is_corruption());
+        assert_eq!(
+            AxError::try_from(LinuxError::EUCLEAN),
+            Ok(AxError::FilesystemCorrupted)
+        );
+    }
+}
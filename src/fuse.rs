@@ -0,0 +1,76 @@
+//! Validation of the error field of FUSE replies.
+//!
+//! The `error` field of `fuse_out_header` is either 0 or a negated error
+//! code. The kernel rejects the whole reply with [`EINVAL`] if the field is
+//! positive or at or below `-512`, i.e. a kernel-internal code.
+//!
+//! [`EINVAL`]: LinuxError::EINVAL
+
+use crate::{LinuxError, LinuxResult};
+
+/// Validates the `error` field of a FUSE reply carrying an error.
+///
+/// Returns the error if `error` is a negated valid code that may be sent to
+/// the kernel, or [`EINVAL`](LinuxError::EINVAL) otherwise, including for 0.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::fuse::validate_fuse_errno;
+/// # use axerrno::LinuxError;
+/// #
+/// assert_eq!(validate_fuse_errno(-2), Ok(LinuxError::ENOENT));
+/// assert_eq!(validate_fuse_errno(2), Err(LinuxError::EINVAL));
+/// assert_eq!(validate_fuse_errno(-512), Err(LinuxError::EINVAL));
+/// ```
+pub fn validate_fuse_errno(error: i32) -> LinuxResult<LinuxError> {
+    if error >= 0 {
+        return Err(LinuxError::EINVAL);
+    }
+    match LinuxError::try_from(error.wrapping_neg()) {
+        Ok(err) if !err.is_kernel_internal() => Ok(err),
+        _ => Err(LinuxError::EINVAL),
+    }
+}
+
+/// Encodes a raw error code of either sign for the `error` field of a FUSE
+/// reply.
+///
+/// Positive codes are negated. Returns [`EINVAL`](LinuxError::EINVAL) if the
+/// code is 0, out of range or kernel-internal.
+pub fn encode_fuse_errno(errno: i32) -> LinuxResult<i32> {
+    let error = if errno > 0 { -errno } else { errno };
+    validate_fuse_errno(error).map(|_| error)
+}
+
+/// Encodes an error for the `error` field of a FUSE reply.
+///
+/// Kernel-internal codes, which the kernel would reject, are reported as
+/// [`EIO`](LinuxError::EIO).
+pub fn fuse_reply_error(err: LinuxError) -> i32 {
+    if err.is_kernel_internal() {
+        -LinuxError::EIO.code()
+    } else {
+        -err.code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuse_errno() {
+        assert_eq!(validate_fuse_errno(0), Err(LinuxError::EINVAL));
+        assert_eq!(validate_fuse_errno(-4000), Err(LinuxError::EINVAL));
+        assert_eq!(validate_fuse_errno(i32::MIN), Err(LinuxError::EINVAL));
+
+        assert_eq!(encode_fuse_errno(5), Ok(-5));
+        assert_eq!(encode_fuse_errno(-5), Ok(-5));
+        assert_eq!(encode_fuse_errno(0), Err(LinuxError::EINVAL));
+        assert_eq!(encode_fuse_errno(516), Err(LinuxError::EINVAL));
+
+        assert_eq!(fuse_reply_error(LinuxError::EEXIST), -17);
+        assert_eq!(fuse_reply_error(LinuxError::ERESTARTSYS), -5);
+    }
+}
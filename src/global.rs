@@ -0,0 +1,49 @@
+//! Set-once registration of global hooks.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{AxError, AxResult};
+
+const UNSET: u8 = 0;
+const SETTING: u8 = 1;
+const SET: u8 = 2;
+
+/// A global `&'static T` that can be set once, like `log::set_logger`.
+pub(crate) struct GlobalRef<T: ?Sized + 'static> {
+    state: AtomicU8,
+    value: UnsafeCell<Option<&'static T>>,
+}
+
+// SAFETY: `value` is only written once, before `state` becomes `SET`, and only
+// read after observing `SET`.
+unsafe impl<T: ?Sized + Sync> Sync for GlobalRef<T> {}
+
+impl<T: ?Sized> GlobalRef<T> {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNSET),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// Sets the value, or returns [`AxError::AlreadyExists`] if it is set.
+    pub fn set(&self, value: &'static T) -> AxResult {
+        self.state
+            .compare_exchange(UNSET, SETTING, Ordering::Acquire, Ordering::Relaxed)
+            .map_err(|_| AxError::AlreadyExists)?;
+        // SAFETY: we are the only writer, and readers wait for `SET`.
+        unsafe { *self.value.get() = Some(value) };
+        self.state.store(SET, Ordering::Release);
+        Ok(())
+    }
+
+    pub fn get(&self) -> Option<&'static T> {
+        if self.state.load(Ordering::Acquire) == SET {
+            // SAFETY: `value` is never written again once `SET`.
+            unsafe { *self.value.get() }
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Short hints on the common causes of errors.
+//!
+//! Used by the `errno` command of the debug shell. The hints are compiled in
+//! only with the `help` feature.
+
+use crate::{AxError, LinuxError};
+
+impl LinuxError {
+    /// Returns a short hint on the common causes of the error, if there is
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::LinuxError;
+    /// #
+    /// assert_eq!(
+    ///     LinuxError::EMFILE.help(),
+    ///     Some("raise RLIMIT_NOFILE or check for fd leaks"),
+    /// );
+    /// assert_eq!(LinuxError::ECHRNG.help(), None);
+    /// ```
+    pub const fn help(self) -> Option<&'static str> {
+        use LinuxError as E;
+        Some(match self {
+            E::EPERM => "the operation needs a capability the task lacks",
+            E::ENOENT => "check the path and that every parent directory exists",
+            E::EINTR => "a signal arrived; retry or use SA_RESTART",
+            E::EIO => "check the device and the kernel log for driver errors",
+            E::E2BIG => "shrink the argument and environment lists",
+            E::ENOEXEC => "check the ELF header, machine type and interpreter",
+            E::EBADF => "the fd is closed or lacks the access mode for the operation",
+            E::EAGAIN => "the resource is temporarily unavailable; retry or poll",
+            E::ENOMEM => "check for leaks or raise the memory limits",
+            E::EACCES => "check the file mode bits and the search permission of parents",
+            E::EFAULT => "a user pointer is invalid or not mapped",
+            E::EBUSY => "the resource is in use, e.g. a mounted filesystem",
+            E::EEXIST => "remove the entry or open without O_EXCL",
+            E::EXDEV => "rename and link only work within one filesystem",
+            E::ENOTDIR => "a path component is not a directory",
+            E::EISDIR => "open directories with O_DIRECTORY or use getdents",
+            E::EINVAL => "check the flags and arguments against the man page",
+            E::ENFILE => "the system-wide file table is full",
+            E::EMFILE => "raise RLIMIT_NOFILE or check for fd leaks",
+            E::ENOTTY => "the ioctl is not supported by this kind of file",
+            E::ENOSPC => "free up space or inodes on the filesystem",
+            E::ESPIPE => "pipes, sockets and FIFOs cannot seek",
+            E::EROFS => "remount the filesystem read-write",
+            E::EPIPE => "the reader has gone away; handle or ignore SIGPIPE",
+            E::ERANGE => "the buffer is too small for the result",
+            E::EDEADLK => "check the lock ordering",
+            E::ENAMETOOLONG => "shorten the path or a component of it",
+            E::ENOSYS => "the syscall is not implemented yet",
+            E::ENOTEMPTY => "remove the directory contents first",
+            E::ELOOP => "check for symlink cycles or use O_NOFOLLOW",
+            E::EUCLEAN => "run fsck on the filesystem",
+            E::ENOTSOCK => "the fd is not a socket",
+            E::EADDRINUSE => "another socket is bound; set SO_REUSEADDR or pick a port",
+            E::EADDRNOTAVAIL => "the address is not assigned to a local interface",
+            E::ENETUNREACH => "check the routes and the interface state",
+            E::ECONNRESET => "the peer closed the connection abruptly",
+            E::ENOTCONN => "connect the socket first",
+            E::ETIMEDOUT => "the peer did not respond; check the network path",
+            E::ECONNREFUSED => "nothing is listening on the remote port",
+            E::EHOSTUNREACH => "check the routes and ARP/NDP resolution",
+            _ => return None,
+        })
+    }
+}
+
+impl AxError {
+    /// Returns a short hint on the common causes of the error, if there is
+    /// one.
+    ///
+    /// This is the hint of the corresponding [`LinuxError`], unless the error
+    /// has a more specific one.
+    pub fn help(self) -> Option<&'static str> {
+        match self {
+            Self::BadState => Some("an internal invariant was broken; this is a kernel bug"),
+            Self::WriteZero => Some("the device accepted no more data; check free space"),
+            Self::UnexpectedEof => Some("the file or stream is truncated"),
+            _ => LinuxError::from(self).help(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_help() {
+        assert_eq!(AxError::NotFound.help(), LinuxError::ENOENT.help());
+        assert!(AxError::BadState.help().unwrap().contains("bug"));
+        assert!(LinuxError::ENOTRECOVERABLE.help().is_none());
+    }
+}
@@ -0,0 +1,39 @@
+//! A lightweight callback on every error raised by the error macros.
+//!
+//! Unlike an [`ErrorSink`](crate::sink::ErrorSink), the hook is a plain
+//! function that can be replaced at any time, e.g. to bump a watchdog, mark a
+//! device degraded after repeated I/O errors, or break into a debugger.
+
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::AxError;
+
+/// The signature of the hook.
+pub type ErrorHook = fn(AxError, &'static Location<'static>);
+
+static HOOK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs the function called with each error raised by [`ax_err!`] and
+/// its variants.
+///
+/// Replaces the previously installed hook, if any.
+///
+/// [`ax_err!`]: crate::ax_err
+pub fn set_hook(hook: ErrorHook) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Removes the installed hook.
+pub fn clear_hook() {
+    HOOK.store(core::ptr::null_mut(), Ordering::Release);
+}
+
+pub(crate) fn call(err: AxError, location: &'static Location<'static>) {
+    let hook = HOOK.load(Ordering::Acquire);
+    if !hook.is_null() {
+        // SAFETY: only `ErrorHook` pointers are stored in `HOOK`.
+        let hook = unsafe { core::mem::transmute::<*mut (), ErrorHook>(hook) };
+        hook(err, location);
+    }
+}
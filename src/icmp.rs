@@ -0,0 +1,184 @@
+//! Error codes reported by ICMP and ICMPv6 error messages.
+//!
+//! When an ICMP error about a sent packet arrives, the network stack reports
+//! an asynchronous error on the originating socket. [`icmp_error`] and
+//! [`icmpv6_error`] give the error code Linux reports for a message type and
+//! code, and whether the error is hard, i.e. reported to connected sockets
+//! even without `IP_RECVERR`.
+
+use crate::LinuxError;
+
+/// The error an ICMP message reports on a socket.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IcmpError {
+    /// The error code.
+    pub errno: LinuxError,
+    /// Whether the error is hard (`fatal` in Linux), aborting connections
+    /// instead of being a transient condition.
+    pub fatal: bool,
+}
+
+impl IcmpError {
+    const fn new(errno: LinuxError, fatal: bool) -> Self {
+        Self { errno, fatal }
+    }
+}
+
+/// ICMP `Destination Unreachable`.
+pub const ICMP_DEST_UNREACH: u8 = 3;
+/// ICMP `Source Quench`.
+pub const ICMP_SOURCE_QUENCH: u8 = 4;
+/// ICMP `Redirect`.
+pub const ICMP_REDIRECT: u8 = 5;
+/// ICMP `Time Exceeded`.
+pub const ICMP_TIME_EXCEEDED: u8 = 11;
+/// ICMP `Parameter Problem`.
+pub const ICMP_PARAMETERPROB: u8 = 12;
+
+/// ICMPv6 `Destination Unreachable`.
+pub const ICMPV6_DEST_UNREACH: u8 = 1;
+/// ICMPv6 `Packet Too Big`.
+pub const ICMPV6_PKT_TOOBIG: u8 = 2;
+/// ICMPv6 `Time Exceeded`.
+pub const ICMPV6_TIME_EXCEED: u8 = 3;
+/// ICMPv6 `Parameter Problem`.
+pub const ICMPV6_PARAMPROB: u8 = 4;
+
+/// The errors of the `Destination Unreachable` codes, indexed by code, as in
+/// Linux's `icmp_err_convert`.
+const ICMP_UNREACH: [IcmpError; 16] = {
+    use LinuxError as E;
+    [
+        IcmpError::new(E::ENETUNREACH, false),  // NET_UNREACH
+        IcmpError::new(E::EHOSTUNREACH, false), // HOST_UNREACH
+        IcmpError::new(E::ENOPROTOOPT, true),   // PROT_UNREACH
+        IcmpError::new(E::ECONNREFUSED, true),  // PORT_UNREACH
+        IcmpError::new(E::EMSGSIZE, false),     // FRAG_NEEDED
+        IcmpError::new(E::EOPNOTSUPP, false),   // SR_FAILED
+        IcmpError::new(E::ENETUNREACH, true),   // NET_UNKNOWN
+        IcmpError::new(E::EHOSTDOWN, true),     // HOST_UNKNOWN
+        IcmpError::new(E::ENONET, true),        // HOST_ISOLATED
+        IcmpError::new(E::ENETUNREACH, true),   // NET_ANO
+        IcmpError::new(E::EHOSTUNREACH, true),  // HOST_ANO
+        IcmpError::new(E::ENETUNREACH, false),  // NET_UNR_TOS
+        IcmpError::new(E::EHOSTUNREACH, false), // HOST_UNR_TOS
+        IcmpError::new(E::EHOSTUNREACH, true),  // PKT_FILTERED
+        IcmpError::new(E::EHOSTUNREACH, true),  // PREC_VIOLATION
+        IcmpError::new(E::EHOSTUNREACH, true),  // PREC_CUTOFF
+    ]
+};
+
+/// The errors of the ICMPv6 `Destination Unreachable` codes, indexed by
+/// code, as in Linux's `icmpv6_err_convert`.
+const ICMPV6_UNREACH: [IcmpError; 7] = {
+    use LinuxError as E;
+    [
+        IcmpError::new(E::ENETUNREACH, false),  // NOROUTE
+        IcmpError::new(E::EACCES, true),        // ADM_PROHIBITED
+        IcmpError::new(E::EHOSTUNREACH, false), // NOT_NEIGHBOUR
+        IcmpError::new(E::EHOSTUNREACH, false), // ADDR_UNREACH
+        IcmpError::new(E::ECONNREFUSED, true),  // PORT_UNREACH
+        IcmpError::new(E::EACCES, true),        // POLICY_FAIL
+        IcmpError::new(E::EACCES, true),        // REJECT_ROUTE
+    ]
+};
+
+/// Returns the error an ICMP message of type `ty` and code `code` reports on
+/// the socket that sent the offending packet.
+///
+/// Returns `None` for messages that report no error to the socket: `Source
+/// Quench`, `Redirect` and the informational messages.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{icmp::{icmp_error, ICMP_DEST_UNREACH}, LinuxError};
+/// #
+/// let err = icmp_error(ICMP_DEST_UNREACH, 3).unwrap();
+/// assert_eq!(err.errno, LinuxError::ECONNREFUSED);
+/// assert!(err.fatal);
+/// assert_eq!(icmp_error(ICMP_DEST_UNREACH, 4).unwrap().errno, LinuxError::EMSGSIZE);
+/// assert_eq!(icmp_error(0, 0), None);
+/// ```
+pub const fn icmp_error(ty: u8, code: u8) -> Option<IcmpError> {
+    match ty {
+        ICMP_DEST_UNREACH if (code as usize) < ICMP_UNREACH.len() => {
+            Some(ICMP_UNREACH[code as usize])
+        }
+        ICMP_DEST_UNREACH | ICMP_TIME_EXCEEDED => {
+            Some(IcmpError::new(LinuxError::EHOSTUNREACH, false))
+        }
+        ICMP_PARAMETERPROB => Some(IcmpError::new(LinuxError::EPROTO, true)),
+        _ => None,
+    }
+}
+
+/// Returns the error an ICMPv6 message of type `ty` and code `code` reports
+/// on the socket that sent the offending packet.
+///
+/// Returns `None` for informational messages (types `128` and above).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{icmp::{icmpv6_error, ICMPV6_PKT_TOOBIG}, LinuxError};
+/// #
+/// let err = icmpv6_error(ICMPV6_PKT_TOOBIG, 0).unwrap();
+/// assert_eq!(err.errno, LinuxError::EMSGSIZE);
+/// assert!(!err.fatal);
+/// ```
+pub const fn icmpv6_error(ty: u8, code: u8) -> Option<IcmpError> {
+    use LinuxError as E;
+    Some(match ty {
+        ICMPV6_DEST_UNREACH if (code as usize) < ICMPV6_UNREACH.len() => {
+            ICMPV6_UNREACH[code as usize]
+        }
+        ICMPV6_DEST_UNREACH => IcmpError::new(E::EPROTO, true),
+        ICMPV6_PKT_TOOBIG => IcmpError::new(E::EMSGSIZE, false),
+        ICMPV6_TIME_EXCEED => IcmpError::new(E::EHOSTUNREACH, false),
+        ICMPV6_PARAMPROB => IcmpError::new(E::EPROTO, true),
+        128.. => return None,
+        _ => IcmpError::new(E::EPROTO, false),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net::{SockExtendedErr, SO_EE_ORIGIN_ICMP};
+
+    #[test]
+    fn test_icmp_error() {
+        use LinuxError as E;
+        let errno = |ty, code| icmp_error(ty, code).map(|e| e.errno);
+        assert_eq!(errno(ICMP_DEST_UNREACH, 0), Some(E::ENETUNREACH));
+        assert_eq!(errno(ICMP_DEST_UNREACH, 1), Some(E::EHOSTUNREACH));
+        assert_eq!(errno(ICMP_DEST_UNREACH, 13), Some(E::EHOSTUNREACH));
+        assert_eq!(errno(ICMP_DEST_UNREACH, 200), Some(E::EHOSTUNREACH));
+        assert_eq!(errno(ICMP_TIME_EXCEEDED, 0), Some(E::EHOSTUNREACH));
+        assert_eq!(errno(ICMP_PARAMETERPROB, 0), Some(E::EPROTO));
+        assert_eq!(errno(ICMP_SOURCE_QUENCH, 0), None);
+        assert_eq!(errno(ICMP_REDIRECT, 1), None);
+
+        let err = icmp_error(ICMP_DEST_UNREACH, 4).unwrap();
+        let ee = SockExtendedErr::icmp(err.errno, ICMP_DEST_UNREACH, 4).with_info(1400);
+        assert_eq!(ee.ee_origin, SO_EE_ORIGIN_ICMP);
+        assert_eq!(ee.errno(), Some(E::EMSGSIZE));
+    }
+
+    #[test]
+    fn test_icmpv6_error() {
+        use LinuxError as E;
+        let unreach = |code| icmpv6_error(ICMPV6_DEST_UNREACH, code).unwrap();
+        assert_eq!(unreach(1), IcmpError::new(E::EACCES, true));
+        assert_eq!(unreach(3), IcmpError::new(E::EHOSTUNREACH, false));
+        assert_eq!(unreach(4), IcmpError::new(E::ECONNREFUSED, true));
+        assert_eq!(unreach(9), IcmpError::new(E::EPROTO, true));
+        assert_eq!(
+            icmpv6_error(ICMPV6_TIME_EXCEED, 0),
+            Some(IcmpError::new(E::EHOSTUNREACH, false))
+        );
+        assert_eq!(icmpv6_error(100, 0), Some(IcmpError::new(E::EPROTO, false)));
+        assert_eq!(icmpv6_error(128, 0), None);
+    }
+}
@@ -0,0 +1,228 @@
+//! Fault injection at error sites.
+//!
+//! With the `fault-inject` feature, every [`ax_err!`] call consults the
+//! registered [`InjectPolicy`], which may substitute the error, and every
+//! [`fault_point!`] may be forced to fail. This reaches VFS and network error
+//! paths that are otherwise hard to trigger in tests.
+//!
+//! Sites are identified by [`ErrorSite::hash`], which only depends on the
+//! source location and is thus stable across builds of the same source.
+//!
+//! [`ax_err!`]: crate::ax_err
+//! [`fault_point!`]: crate::fault_point
+
+use core::panic::Location;
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use crate::global::GlobalRef;
+pub use crate::site::site_hash;
+use crate::{AxError, AxResult};
+
+/// A site an error may be injected at.
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorSite {
+    /// The source location of the site.
+    pub location: &'static Location<'static>,
+    /// The error constructed at the site, or `None` for a [`fault_point!`].
+    ///
+    /// [`fault_point!`]: crate::fault_point
+    pub err: Option<AxError>,
+}
+
+impl ErrorSite {
+    /// Returns the stable hash of the site location.
+    pub const fn hash(&self) -> u64 {
+        site_hash(
+            self.location.file(),
+            self.location.line(),
+            self.location.column(),
+        )
+    }
+}
+
+/// Decides which errors to inject.
+pub trait InjectPolicy: Sync {
+    /// Returns the error to inject at `site`, or `None` to leave it as is.
+    fn inject(&self, site: &ErrorSite) -> Option<AxError>;
+}
+
+static POLICY: GlobalRef<dyn InjectPolicy> = GlobalRef::new();
+
+/// Registers the injection policy.
+///
+/// Returns [`AxError::AlreadyExists`] if a policy is already registered.
+pub fn set_policy(policy: &'static dyn InjectPolicy) -> AxResult {
+    POLICY.set(policy)
+}
+
+/// When an [`InjectRule`] fires.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Trigger {
+    /// On every match.
+    Always,
+    /// On every `n`-th match.
+    EveryNth(u32),
+    /// On a match with the given probability in percent.
+    Probability(u8),
+}
+
+/// A rule of a [`RuleSet`].
+///
+/// A rule without a site or error filter matches every site, including
+/// [`fault_point!`](crate::fault_point)s.
+#[derive(Debug)]
+pub struct InjectRule {
+    site: Option<u64>,
+    on_error: Option<AxError>,
+    inject: AxError,
+    trigger: Trigger,
+    matches: AtomicU32,
+}
+
+impl InjectRule {
+    /// Creates a rule injecting `inject` when `trigger` fires.
+    pub const fn new(inject: AxError, trigger: Trigger) -> Self {
+        Self {
+            site: None,
+            on_error: None,
+            inject,
+            trigger,
+            matches: AtomicU32::new(0),
+        }
+    }
+
+    /// Only matches the site with the given [hash](ErrorSite::hash).
+    pub const fn at_site(mut self, hash: u64) -> Self {
+        self.site = Some(hash);
+        self
+    }
+
+    /// Only matches sites constructing `err`, substituting it.
+    pub const fn on_error(mut self, err: AxError) -> Self {
+        self.on_error = Some(err);
+        self
+    }
+
+    fn matches(&self, site: &ErrorSite) -> bool {
+        self.site.is_none_or(|hash| hash == site.hash())
+            && self.on_error.is_none_or(|err| site.err == Some(err))
+    }
+}
+
+/// An [`InjectPolicy`] applying the first matching rule that fires.
+///
+/// Probabilistic rules draw from a pseudo-random sequence determined by the
+/// seed, so a run is reproducible given the same order of events.
+///
+/// # Examples
+///
+/// ```
+/// use axerrno::inject::{site_hash, InjectRule, RuleSet, Trigger};
+/// use axerrno::AxError;
+///
+/// static RULES: [InjectRule; 2] = [
+///     // Make every third lookup miss.
+///     InjectRule::new(AxError::NotFound, Trigger::EveryNth(3))
+///         .at_site(site_hash("src/fs/lookup.rs", 42, 9)),
+///     // Turn half of the `WouldBlock`s into `ConnectionReset`s.
+///     InjectRule::new(AxError::ConnectionReset, Trigger::Probability(50))
+///         .on_error(AxError::WouldBlock),
+/// ];
+/// static POLICY: RuleSet = RuleSet::new(&RULES, 1);
+/// axerrno::inject::set_policy(&POLICY).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RuleSet {
+    rules: &'static [InjectRule],
+    state: AtomicU64,
+}
+
+impl RuleSet {
+    /// Creates a rule set with the seed of its pseudo-random sequence.
+    pub const fn new(rules: &'static [InjectRule], seed: u64) -> Self {
+        Self {
+            rules,
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Returns a number in `0..100` (splitmix64).
+    fn roll(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) % 100
+    }
+}
+
+impl InjectPolicy for RuleSet {
+    fn inject(&self, site: &ErrorSite) -> Option<AxError> {
+        self.rules.iter().find_map(|rule| {
+            if !rule.matches(site) {
+                return None;
+            }
+            let fires = match rule.trigger {
+                Trigger::Always => true,
+                Trigger::EveryNth(n) => {
+                    (rule.matches.fetch_add(1, Ordering::Relaxed) + 1) % n.max(1) == 0
+                }
+                Trigger::Probability(percent) => self.roll() < percent as u64,
+            };
+            fires.then_some(rule.inject)
+        })
+    }
+}
+
+#[doc(hidden)]
+#[cold]
+pub fn __substitute(err: AxError, location: &'static Location<'static>) -> AxError {
+    let site = ErrorSite {
+        location,
+        err: Some(err),
+    };
+    POLICY
+        .get()
+        .and_then(|policy| policy.inject(&site))
+        .unwrap_or(err)
+}
+
+#[doc(hidden)]
+pub fn __fault_point(location: &'static Location<'static>) -> AxResult {
+    let site = ErrorSite {
+        location,
+        err: None,
+    };
+    match POLICY.get().and_then(|policy| policy.inject(&site)) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_set() {
+        static RULES: [InjectRule; 2] = [
+            InjectRule::new(AxError::Io, Trigger::EveryNth(2)).on_error(AxError::NotFound),
+            InjectRule::new(AxError::Timeout, Trigger::Probability(0)),
+        ];
+        let rules = RuleSet::new(&RULES, 0);
+        let site = ErrorSite {
+            location: Location::caller(),
+            err: Some(AxError::NotFound),
+        };
+        assert_eq!(rules.inject(&site), None);
+        assert_eq!(rules.inject(&site), Some(AxError::Io));
+        assert_eq!(rules.inject(&ErrorSite { err: None, ..site }), None);
+
+        let at_site = InjectRule::new(AxError::Busy, Trigger::Always).at_site(site.hash());
+        assert!(at_site.matches(&site));
+        let loc = site.location;
+        assert_eq!(site.hash(), site_hash(loc.file(), loc.line(), loc.column()));
+    }
+}
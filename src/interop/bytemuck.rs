@@ -0,0 +1,53 @@
+use ::bytemuck::{CheckedBitPattern, NoUninit, Pod, Zeroable};
+
+use crate::{AxError, Errno, LinuxError};
+
+// SAFETY: `AxError` is a fieldless `repr(i32)` enum, and the valid bit
+// patterns are exactly the codes accepted by `TryFrom<i32>`.
+unsafe impl NoUninit for AxError {}
+unsafe impl CheckedBitPattern for AxError {
+    type Bits = i32;
+
+    fn is_valid_bit_pattern(bits: &i32) -> bool {
+        AxError::try_from(*bits).is_ok()
+    }
+}
+
+// SAFETY: `LinuxError` is a `repr(transparent)` wrapper of an `i32` in
+// `1..=MAX_ERRNO`.
+unsafe impl NoUninit for LinuxError {}
+unsafe impl CheckedBitPattern for LinuxError {
+    type Bits = i32;
+
+    fn is_valid_bit_pattern(bits: &i32) -> bool {
+        LinuxError::new(*bits).is_some()
+    }
+}
+
+// SAFETY: `Errno` is a `repr(transparent)` wrapper of any `i32`.
+unsafe impl Zeroable for Errno {}
+unsafe impl Pod for Errno {}
+
+#[cfg(test)]
+mod tests {
+    use ::bytemuck::checked;
+
+    use super::*;
+
+    #[test]
+    fn test_bytemuck() {
+        let bytes = 30_i32.to_ne_bytes();
+        assert_eq!(checked::try_from_bytes(&bytes), Ok(&AxError::NotSupported));
+        let bytes = 1000_i32.to_ne_bytes();
+        assert!(checked::try_from_bytes::<AxError>(&bytes).is_err());
+
+        let bytes = 4095_i32.to_ne_bytes();
+        assert_eq!(
+            checked::try_from_bytes::<LinuxError>(&bytes).map(|e| e.code()),
+            Ok(4095)
+        );
+        assert!(checked::try_from_bytes::<LinuxError>(&[0; 4]).is_err());
+        assert_eq!(::bytemuck::bytes_of(&LinuxError::EIO), 5_i32.to_ne_bytes());
+        assert_eq!(*::bytemuck::from_bytes::<Errno>(&[0xff; 4]), Errno(-1));
+    }
+}
@@ -0,0 +1,105 @@
+use ::embedded_hal::i2c::NoAcknowledgeSource;
+use ::embedded_hal::{digital, i2c, spi};
+
+use crate::{AxError, LinuxError};
+
+/// Follows the I2C fault codes of Linux: [`Bus`](i2c::ErrorKind::Bus) is
+/// `EIO`, [`ArbitrationLoss`](i2c::ErrorKind::ArbitrationLoss) is `EAGAIN`,
+/// a missing acknowledge of the address is `ENXIO` and of the data
+/// `EREMOTEIO`. Other errors are `EIO`.
+impl From<i2c::ErrorKind> for LinuxError {
+    fn from(kind: i2c::ErrorKind) -> Self {
+        match kind {
+            i2c::ErrorKind::ArbitrationLoss => LinuxError::EAGAIN,
+            i2c::ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => LinuxError::EREMOTEIO,
+            i2c::ErrorKind::NoAcknowledge(_) => LinuxError::ENXIO,
+            _ => LinuxError::EIO,
+        }
+    }
+}
+
+/// As the [`LinuxError`] conversion, except that a missing acknowledge of the
+/// data, which has no [`AxError`], becomes [`Io`](AxError::Io).
+impl From<i2c::ErrorKind> for AxError {
+    fn from(kind: i2c::ErrorKind) -> Self {
+        match kind {
+            i2c::ErrorKind::ArbitrationLoss => AxError::Again,
+            i2c::ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => AxError::Io,
+            i2c::ErrorKind::NoAcknowledge(_) => AxError::NoSuchDeviceOrAddress,
+            _ => AxError::Io,
+        }
+    }
+}
+
+/// Maps [`ModeFault`](spi::ErrorKind::ModeFault), a conflict with another
+/// bus master, to `EBUSY`, [`FrameFormat`](spi::ErrorKind::FrameFormat) to
+/// `EINVAL`, and other errors to `EIO`.
+impl From<spi::ErrorKind> for LinuxError {
+    fn from(kind: spi::ErrorKind) -> Self {
+        match kind {
+            spi::ErrorKind::ModeFault => LinuxError::EBUSY,
+            spi::ErrorKind::FrameFormat => LinuxError::EINVAL,
+            _ => LinuxError::EIO,
+        }
+    }
+}
+
+impl From<spi::ErrorKind> for AxError {
+    fn from(kind: spi::ErrorKind) -> Self {
+        match kind {
+            spi::ErrorKind::ModeFault => AxError::Busy,
+            spi::ErrorKind::FrameFormat => AxError::InvalidInput,
+            _ => AxError::Io,
+        }
+    }
+}
+
+impl From<digital::ErrorKind> for LinuxError {
+    fn from(_: digital::ErrorKind) -> Self {
+        LinuxError::EIO
+    }
+}
+
+impl From<digital::ErrorKind> for AxError {
+    fn from(_: digital::ErrorKind) -> Self {
+        AxError::Io
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_hal_error_kind() {
+        use i2c::ErrorKind as I2c;
+
+        assert_eq!(AxError::from(I2c::Bus), AxError::Io);
+        assert_eq!(AxError::from(I2c::ArbitrationLoss), AxError::Again);
+        assert_eq!(
+            AxError::from(I2c::NoAcknowledge(NoAcknowledgeSource::Address)),
+            AxError::NoSuchDeviceOrAddress
+        );
+        assert_eq!(
+            LinuxError::from(I2c::NoAcknowledge(NoAcknowledgeSource::Unknown)),
+            LinuxError::ENXIO
+        );
+        assert_eq!(
+            LinuxError::from(I2c::NoAcknowledge(NoAcknowledgeSource::Data)),
+            LinuxError::EREMOTEIO
+        );
+        assert_eq!(
+            AxError::from(I2c::NoAcknowledge(NoAcknowledgeSource::Data)),
+            AxError::Io
+        );
+        assert_eq!(AxError::from(I2c::Overrun), AxError::Io);
+
+        assert_eq!(AxError::from(spi::ErrorKind::ModeFault), AxError::Busy);
+        assert_eq!(
+            LinuxError::from(spi::ErrorKind::FrameFormat),
+            LinuxError::EINVAL
+        );
+        assert_eq!(AxError::from(spi::ErrorKind::Overrun), AxError::Io);
+        assert_eq!(AxError::from(digital::ErrorKind::Other), AxError::Io);
+    }
+}
@@ -0,0 +1,28 @@
+use ::getrandom::Error;
+
+use crate::{AxError, LinuxError};
+
+/// Maps [`Error`] to [`AxError::WouldBlock`] if the entropy pool is not
+/// initialized yet (`EAGAIN`), and to [`AxError::Io`] otherwise.
+impl From<Error> for AxError {
+    fn from(e: Error) -> Self {
+        match e.raw_os_error() {
+            Some(code) if code == LinuxError::EAGAIN.code() => AxError::WouldBlock,
+            _ => AxError::Io,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use super::*;
+
+    #[test]
+    fn test_getrandom_error() {
+        let not_ready = Error::from(NonZeroU32::new(11).unwrap());
+        assert_eq!(AxError::from(not_ready), AxError::WouldBlock);
+        assert_eq!(AxError::from(Error::UNSUPPORTED), AxError::Io);
+    }
+}
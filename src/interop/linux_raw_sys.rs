@@ -0,0 +1,64 @@
+use ::linux_raw_sys::errno;
+
+use crate::LinuxError;
+
+/// Asserts at compile time that the named codes agree with `linux_raw_sys`.
+macro_rules! verify {
+    ($($name: ident)*) => {
+        $(
+            const _: () = assert!(
+                LinuxError::$name.code() as u32 == errno::$name,
+                concat!("`LinuxError::", stringify!($name), "` disagrees with `linux_raw_sys`"),
+            );
+        )*
+    };
+}
+
+verify! {
+    EPERM ENOENT ESRCH EINTR EIO ENXIO E2BIG ENOEXEC EBADF ECHILD EAGAIN ENOMEM EACCES
+    EFAULT ENOTBLK EBUSY EEXIST EXDEV ENODEV ENOTDIR EISDIR EINVAL ENFILE EMFILE ENOTTY
+    ETXTBSY EFBIG ENOSPC ESPIPE EROFS EMLINK EPIPE EDOM ERANGE EDEADLK ENAMETOOLONG ENOLCK
+    ENOSYS ENOTEMPTY ELOOP EWOULDBLOCK ENOMSG EIDRM ECHRNG EL2NSYNC EL3HLT EL3RST ELNRNG
+    EUNATCH ENOCSI EL2HLT EBADE EBADR EXFULL ENOANO EBADRQC EBADSLT EDEADLOCK EBFONT ENOSTR
+    ENODATA ETIME ENOSR ENONET ENOPKG EREMOTE ENOLINK EADV ESRMNT ECOMM EPROTO EMULTIHOP
+    EDOTDOT EBADMSG EOVERFLOW ENOTUNIQ EBADFD EREMCHG ELIBACC ELIBBAD ELIBSCN ELIBMAX
+    ELIBEXEC EILSEQ ERESTART ESTRPIPE EUSERS ENOTSOCK EDESTADDRREQ EMSGSIZE EPROTOTYPE
+    ENOPROTOOPT EPROTONOSUPPORT ESOCKTNOSUPPORT EOPNOTSUPP EPFNOSUPPORT EAFNOSUPPORT
+    EADDRINUSE EADDRNOTAVAIL ENETDOWN ENETUNREACH ENETRESET ECONNABORTED ECONNRESET ENOBUFS
+    EISCONN ENOTCONN ESHUTDOWN ETOOMANYREFS ETIMEDOUT ECONNREFUSED EHOSTDOWN EHOSTUNREACH
+    EALREADY EINPROGRESS ESTALE EUCLEAN ENOTNAM ENAVAIL EISNAM EREMOTEIO EDQUOT ENOMEDIUM
+    EMEDIUMTYPE ECANCELED ENOKEY EKEYEXPIRED EKEYREVOKED EKEYREJECTED EOWNERDEAD
+    ENOTRECOVERABLE ERFKILL EHWPOISON
+}
+
+impl LinuxError {
+    /// Converts a `linux_raw_sys::errno` constant into a `LinuxError`, or
+    /// returns `None` if it is not in `1..=MAX_ERRNO`.
+    pub const fn from_raw_sys(code: u32) -> Option<Self> {
+        if code > i32::MAX as u32 {
+            return None;
+        }
+        Self::new(code as i32)
+    }
+
+    /// Returns the code as a `linux_raw_sys::errno` constant.
+    pub const fn to_raw_sys(self) -> u32 {
+        self.code() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linux_raw_sys() {
+        assert_eq!(
+            LinuxError::from_raw_sys(errno::ENOENT),
+            Some(LinuxError::ENOENT)
+        );
+        assert_eq!(LinuxError::from_raw_sys(0), None);
+        assert_eq!(LinuxError::from_raw_sys(u32::MAX), None);
+        assert_eq!(LinuxError::EHWPOISON.to_raw_sys(), errno::EHWPOISON);
+    }
+}
@@ -0,0 +1,20 @@
+//! Conversions to and from error types of other crates.
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "embedded-hal")]
+mod embedded_hal;
+#[cfg(feature = "getrandom")]
+mod getrandom;
+#[cfg(feature = "linux-raw-sys")]
+mod linux_raw_sys;
+#[cfg(feature = "nix")]
+mod nix;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "rand_core")]
+mod rand_core;
+#[cfg(feature = "rustix")]
+mod rustix;
+#[cfg(feature = "ufmt")]
+mod ufmt;
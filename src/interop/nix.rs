@@ -0,0 +1,39 @@
+use ::nix::errno::Errno;
+
+use crate::{AxError, LinuxError};
+
+impl From<LinuxError> for Errno {
+    fn from(e: LinuxError) -> Self {
+        Errno::from_raw(e.code())
+    }
+}
+
+impl From<AxError> for Errno {
+    fn from(e: AxError) -> Self {
+        LinuxError::from(e).into()
+    }
+}
+
+impl TryFrom<Errno> for LinuxError {
+    type Error = Errno;
+
+    fn try_from(e: Errno) -> Result<Self, Self::Error> {
+        LinuxError::try_from(e as i32).map_err(|_| e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nix_errno() {
+        assert_eq!(Errno::from(LinuxError::ENOENT), Errno::ENOENT);
+        assert_eq!(Errno::from(AxError::WouldBlock), Errno::EAGAIN);
+        assert_eq!(LinuxError::try_from(Errno::EACCES), Ok(LinuxError::EACCES));
+        assert_eq!(
+            LinuxError::try_from(Errno::UnknownErrno),
+            Err(Errno::UnknownErrno)
+        );
+    }
+}
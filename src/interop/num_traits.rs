@@ -0,0 +1,46 @@
+use ::num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::LinuxError;
+
+impl FromPrimitive for LinuxError {
+    fn from_i64(n: i64) -> Option<Self> {
+        LinuxError::new(n.try_into().ok()?)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        LinuxError::new(n.try_into().ok()?)
+    }
+
+    fn from_i32(n: i32) -> Option<Self> {
+        LinuxError::new(n)
+    }
+}
+
+impl ToPrimitive for LinuxError {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.code().into())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.code() as u64)
+    }
+
+    fn to_i32(&self) -> Option<i32> {
+        Some(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_traits() {
+        assert_eq!(LinuxError::from_u8(2), Some(LinuxError::ENOENT));
+        assert_eq!(LinuxError::from_i64(-2), None);
+        assert_eq!(LinuxError::from_u64(u64::MAX), None);
+        assert_eq!(LinuxError::from_i32(4096), None);
+        assert_eq!(LinuxError::EBADF.to_u8(), Some(9));
+        assert_eq!(LinuxError::new(4095).unwrap().to_u8(), None);
+    }
+}
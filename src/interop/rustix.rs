@@ -0,0 +1,35 @@
+use ::rustix::io::Errno;
+
+use crate::{AxError, LinuxError};
+
+impl From<LinuxError> for Errno {
+    fn from(e: LinuxError) -> Self {
+        Errno::from_raw_os_error(e.code())
+    }
+}
+
+impl From<AxError> for Errno {
+    fn from(e: AxError) -> Self {
+        LinuxError::from(e).into()
+    }
+}
+
+impl TryFrom<Errno> for LinuxError {
+    type Error = Errno;
+
+    fn try_from(e: Errno) -> Result<Self, Self::Error> {
+        LinuxError::try_from(e.raw_os_error()).map_err(|_| e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rustix_errno() {
+        assert_eq!(Errno::from(LinuxError::ENOENT), Errno::NOENT);
+        assert_eq!(Errno::from(AxError::WouldBlock), Errno::AGAIN);
+        assert_eq!(LinuxError::try_from(Errno::ACCESS), Ok(LinuxError::EACCES));
+    }
+}
@@ -0,0 +1,92 @@
+use ::ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+use crate::{AxError, Errno, LinuxError};
+
+impl uDisplay for AxError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.message())
+    }
+}
+
+impl uDisplay for LinuxError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.message())
+    }
+}
+
+impl uDebug for LinuxError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => uwrite!(f, "LinuxError({})", self.code()),
+        }
+    }
+}
+
+impl uDisplay for Errno {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self.linux() {
+            Some(e) if e.is_known() => uDisplay::fmt(&e, f),
+            _ => uwrite!(f, "Unknown error {}", self.0),
+        }
+    }
+}
+
+impl uDebug for Errno {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self.linux() {
+            Some(e) if e.is_known() => uDebug::fmt(&e, f),
+            _ => uwrite!(f, "Errno({})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::ufmt::{uWrite, uwrite};
+
+    use super::*;
+
+    struct Buf([u8; 64], usize);
+
+    impl Buf {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.0[..self.1]).unwrap()
+        }
+    }
+
+    impl uWrite for Buf {
+        type Error = ();
+
+        fn write_str(&mut self, s: &str) -> Result<(), ()> {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(())?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+
+    fn render(f: impl FnOnce(&mut Buf) -> Result<(), ()>) -> Buf {
+        let mut buf = Buf([0; 64], 0);
+        f(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_ufmt() {
+        let ax = render(|w| uwrite!(w, "{:?}: {}", AxError::NotFound, AxError::NotFound));
+        assert_eq!(ax.as_str(), "NotFound: Entity not found");
+        let linux = render(|w| uwrite!(w, "{:?}: {}", LinuxError::EBADF, LinuxError::EBADF));
+        assert_eq!(
+            linux.as_str().strip_prefix("EBADF: "),
+            Some(LinuxError::EBADF.as_str())
+        );
+        let unnamed = render(|w| uwrite!(w, "{:?}", LinuxError::new(4000).unwrap()));
+        assert_eq!(unnamed.as_str(), "LinuxError(4000)");
+        let errno = render(|w| uwrite!(w, "{:?} {:?}", Errno(2), Errno(-1)));
+        assert_eq!(errno.as_str(), "ENOENT Errno(-1)");
+    }
+}
@@ -0,0 +1,67 @@
+//! Encoding of results in io_uring completion queue entries.
+//!
+//! The `res` field of `struct io_uring_cqe` is an `i32`: non-negative values
+//! are results, negative values are negated error codes. Results above
+//! `i32::MAX` cannot be represented; Linux avoids them by capping transfer
+//! sizes at `MAX_RW_COUNT`, and [`encode_cqe_res`] reports them as
+//! [`EOVERFLOW`](LinuxError::EOVERFLOW).
+
+use crate::{LinuxError, LinuxResult};
+
+/// Encodes a result into the `res` field of a completion queue entry.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{io_uring::encode_cqe_res, LinuxError};
+/// #
+/// assert_eq!(encode_cqe_res(Ok(4096)), 4096);
+/// assert_eq!(encode_cqe_res(Err(LinuxError::ECANCELED)), -125);
+/// assert_eq!(encode_cqe_res(Ok(u32::MAX)), -LinuxError::EOVERFLOW.code());
+/// ```
+pub const fn encode_cqe_res(res: LinuxResult<u32>) -> i32 {
+    match res {
+        Ok(value) if value <= i32::MAX as u32 => value as i32,
+        Ok(_) => -LinuxError::EOVERFLOW.code(),
+        Err(err) => -err.code(),
+    }
+}
+
+/// Decodes the `res` field of a completion queue entry.
+///
+/// Negative values below `-MAX_ERRNO` decode to [`EIO`](LinuxError::EIO); use
+/// [`decode_cqe_res_raw`] to preserve them.
+pub fn decode_cqe_res(res: i32) -> LinuxResult<u32> {
+    decode_cqe_res_raw(res).map_err(|code| LinuxError::try_from(code).unwrap_or(LinuxError::EIO))
+}
+
+/// Decodes the `res` field of a completion queue entry, returning the raw
+/// positive error code on failure.
+pub const fn decode_cqe_res_raw(res: i32) -> Result<u32, i32> {
+    if res >= 0 {
+        Ok(res as u32)
+    } else {
+        // `i32::MIN` has no positive counterpart and is not a valid code.
+        Err(res.wrapping_neg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cqe_res() {
+        for res in [Ok(0), Ok(i32::MAX as u32), Err(LinuxError::EAGAIN)] {
+            assert_eq!(decode_cqe_res(encode_cqe_res(res)), res);
+        }
+        assert_eq!(
+            decode_cqe_res(encode_cqe_res(Ok(i32::MAX as u32 + 1))),
+            Err(LinuxError::EOVERFLOW)
+        );
+        assert_eq!(decode_cqe_res(-4000), Err(LinuxError::new(4000).unwrap()));
+        assert_eq!(decode_cqe_res(-5000), Err(LinuxError::EIO));
+        assert_eq!(decode_cqe_res_raw(-4000), Err(4000));
+        assert_eq!(decode_cqe_res_raw(i32::MIN), Err(i32::MIN));
+    }
+}
@@ -7,27 +7,248 @@
 //! - [`LinuxError`] and [`LinuxResult`]: Linux specific error codes defined in
 //!   `errno.h`. It can be converted from [`AxError`].
 //!
+//! [`Errno`] holds any raw error number, including ones outside the range of
+//! [`LinuxError`].
+//!
+//! # Propagating errors across the two types
+//!
+//! `?` converts an [`AxError`] into a [`LinuxError`] through `From`, so an
+//! [`AxResult`] can be propagated from a function returning a
+//! [`LinuxResult`] as is. The other direction is not lossless, as not every
+//! [`LinuxError`] has an [`AxError`], so the conversion is chosen explicitly
+//! with [`LinuxResultExt::into_ax_lossy`] or [`LinuxResultExt::into_ax`]:
+//!
+//! ```
+//! # use axerrno::{AxError, AxResult, LinuxError, LinuxResult, LinuxResultExt};
+//! #
+//! fn lookup() -> AxResult<u32> {
+//!     Err(AxError::NotFound)
+//! }
+//!
+//! fn sys_stat() -> LinuxResult<u32> {
+//!     Ok(lookup()?)
+//! }
+//!
+//! fn read_block() -> LinuxResult<u32> {
+//!     Err(LinuxError::EIO)
+//! }
+//!
+//! fn load() -> AxResult<u32> {
+//!     read_block().into_ax_lossy()
+//! }
+//!
+//! assert_eq!(sys_stat(), Err(LinuxError::ENOENT));
+//! assert_eq!(load(), Err(AxError::Io));
+//! ```
+//!
+//! `FromResidual` cannot make `?` do this implicitly: the coherence rules
+//! forbid implementing it for `Result` outside of `core`, where it is already
+//! implemented through `From`, and `From<LinuxError> for AxError` would
+//! conflict with the lossless `TryFrom` conversion.
+//!
+//! Out-of-tree code ranges can be registered via the [`domain`] module.
+//!
+//! # Cargo features
+//!
+//! - `log` (default): Log the errors raised by [`ax_err!`] and the other error
+//!   macros with the `log` crate, and the `log_err` and `tap_err_warn`
+//!   methods of [`AxResultExt`] and [`LinuxResultExt`]. Without it, the
+//!   macros still construct the errors and call the [`hook`] and [`sink`],
+//!   but print nothing.
+//!
+//! With `default-features = false` and no other features, the crate has no
+//! dependencies and only provides the error types, their conversions and the
+//! dependency-free modules, which suits bootloaders and SBI firmware that
+//! just need the codes.
+//!
+//! - `glibc-strerror`: Make [`LinuxError::as_str`] (and thus its `Display`
+//!   implementation) return the messages of glibc's `strerror(3)` instead of
+//!   the kernel header descriptions. The glibc messages are always available
+//!   via [`LinuxError::strerror`].
+//! - `syscall-errnos`: The `sysno` module with the error codes each syscall
+//!   is documented to return.
+//! - `deferred-log`: Make [`ax_err!`] and the other error macros record the
+//!   error in the [`deferred`] ring instead of logging it, so they are safe to
+//!   use in interrupt handlers.
+//! - `compact-log`: Make the error macros log only the [`site`] ID and error
+//!   number, without the message and fields, and emit the decode table of the
+//!   sites into a link section. Implies `log`.
+//! - `help`: `help()` hints on the common causes of [`AxError`]s and
+//!   [`LinuxError`]s.
+//! - `pretty`: The `pretty` module rendering errors with ANSI colors for the
+//!   debug console.
+//! - `stats`: The `stats` module counting the errors raised by the error
+//!   macros, with their last-seen locations.
+//! - `fault-inject`: The `inject` module, letting a registered policy
+//!   substitute the errors of [`ax_err!`] and fail [`fault_point!`]s.
+//! - `optee`: The `tee` module mapping GlobalPlatform TEE result codes, as
+//!   returned by OP-TEE.
+//! - `rustix`: Conversions to and from [`rustix::io::Errno`].
+//! - `linux-raw-sys`: Conversions to and from the [`linux_raw_sys::errno`]
+//!   constants, and build-time checks that they agree with [`LinuxError`].
+//! - `nix`: Conversions to and from [`nix::errno::Errno`].
+//! - `async`: [`retry::retry_backoff`], retrying asynchronous operations
+//!   with exponential backoff.
+//! - `std`: The `exit` module choosing the exit status of hosted tools
+//!   returning an [`AxResult`] or [`LinuxResult`] from `main`, and the
+//!   `export` module exporting the error tables as JSON and CSV.
+//! - `cli`: The `errno` command, which looks up errors by number or name,
+//!   prints the mapping between [`AxError`] and [`LinuxError`] and dumps the
+//!   table, e.g. `cargo run --features cli --bin errno -- ENOENT`.
+//! - `alloc`: Conversions from `AllocError` and `TryReserveError` into
+//!   [`AxError::NoMemory`], so fallible allocations can use `?`.
+//! - `capi`: The `capi` module exporting `extern "C"` functions to look up
+//!   error messages and names from C.
+//! - `num-traits`: Implement `FromPrimitive` and `ToPrimitive` for
+//!   [`LinuxError`], accepting only codes in `1..=MAX_ERRNO`.
+//! - `getrandom`, `rand_core`: Conversions from [`getrandom::Error`] and
+//!   [`rand_core::Error`] into [`AxError::WouldBlock`] if the entropy pool is
+//!   not ready, and [`AxError::Io`] otherwise.
+//! - `embedded-hal`: Conversions from the I2C, SPI and digital
+//!   `embedded_hal` error kinds into [`AxError`] and [`LinuxError`],
+//!   following the I2C fault codes of Linux, for reusing peripheral drivers
+//!   written against `embedded-hal`.
+//! - `ufmt`: Implement `uDisplay` and `uDebug` for [`AxError`],
+//!   [`LinuxError`] and [`Errno`], for targets logging with `ufmt` instead of
+//!   `core::fmt`.
+//! - `bytemuck`: Implement `CheckedBitPattern` and `NoUninit` for [`AxError`]
+//!   and [`LinuxError`], and `Pod` for [`Errno`], so codes embedded in DMA
+//!   descriptors and shared-memory queues can be read without `unsafe`.
+//! - `zerocopy`: Derive `TryFromBytes` and `IntoBytes` for [`AxError`],
+//!   `IntoBytes` for [`LinuxError`] and `FromBytes` for [`Errno`] for the same
+//!   purpose. Read a [`LinuxError`] as an [`Errno`] and validate it with
+//!   [`Errno::linux`].
+//!
+//! # Build configuration
+//!
+//! By default [`LinuxError`] is generated from a vendored copy of the kernel
+//! uapi headers. Set `AXERRNO_LINUX_UAPI` to a uapi include directory (e.g.
+//! `linux/include/uapi` of a kernel source tree, or `/usr/include`) to
+//! regenerate it from `asm-generic/errno-base.h` and `asm-generic/errno.h`
+//! under that directory instead.
+//!
+//! Set `AXERRNO_ERRNO_TABLE` to a CSV file to add or redefine codes. Each
+//! line holds `name,number,message`; blank lines and lines starting with `#`
+//! are ignored. An entry named after an existing code replaces it, the others
+//! are added:
+//!
+//! ```text
+//! # name,number,message
+//! ECAPREVOKED,200,Capability revoked
+//! ENOTSUPP,524,Operation not supported by this ABI
+//! ```
+//!
+//! [`rustix::io::Errno`]: https://docs.rs/rustix/latest/rustix/io/struct.Errno.html
+//! [`nix::errno::Errno`]: https://docs.rs/nix/latest/nix/errno/enum.Errno.html
 //! [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
 
 #![no_std]
 #![feature(variant_count)]
+#![cfg_attr(feature = "alloc", feature(allocator_api))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::fmt;
 
+pub mod acpi;
+pub mod ata;
+pub mod binder;
+pub mod block;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod class;
+pub mod clock;
+pub mod context;
+pub mod deferred;
+pub mod domain;
+#[cfg(feature = "std")]
+pub mod exit;
+#[cfg(feature = "std")]
+pub mod export;
+pub mod fs;
+pub mod fuse;
+#[cfg(feature = "help")]
+pub mod help;
+pub mod hook;
+pub mod icmp;
+#[cfg(feature = "fault-inject")]
+pub mod inject;
+pub mod io_uring;
+pub mod loader;
+pub mod message;
+pub mod mm;
+pub mod net;
+pub mod netlink;
+pub mod packed;
+pub mod personality;
+pub mod prelude;
+#[cfg(feature = "pretty")]
+pub mod pretty;
+pub mod ratelimit;
+pub mod raw;
+pub mod report;
+pub mod resolver;
+pub mod retry;
+pub mod rfl;
+pub mod scmi;
+pub mod seccomp;
+pub mod set;
+pub mod signal;
+pub mod sink;
+pub mod site;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod subsystem;
+pub mod syscall;
+pub mod sysexits;
+#[cfg(feature = "syscall-errnos")]
+pub mod sysno;
+#[cfg(feature = "optee")]
+pub mod tee;
+pub mod user;
+pub mod wire;
+pub mod xen;
+
+mod errno;
+mod ext;
+mod global;
+mod interop;
 mod linux_errno {
     include!(concat!(env!("OUT_DIR"), "/linux_errno.rs"));
 }
+mod located;
 
-pub use linux_errno::LinuxError;
+pub use errno::{Errno, ErrnoCode};
+pub use ext::{AxResultExt, LinuxResultExt, OptionExt, PollExt};
+pub use linux_errno::{LinuxError, LINUX_ERRNO_TABLE};
+pub use located::{AxErrorAt, AxResultAt, ErrorTrace, TraceExt};
 
 /// The error type used by ArceOS.
 ///
 /// Similar to [`std::io::ErrorKind`].
 ///
+/// Every variant is a fixed code, and there is no catch-all variant carrying a
+/// payload. Codes without an equivalent, such as vendor or foreign-OS codes,
+/// are carried as a [`LinuxError`] or, outside of `1..=MAX_ERRNO`, an
+/// [`Errno`] until the boundary where they are translated.
+///
 /// [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
 #[repr(i32)]
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::TryFromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 pub enum AxError {
     /// A socket address could not be bound because the address is already in use elsewhere.
     AddrInUse = 1,
@@ -93,6 +314,157 @@ pub enum AxError {
     Again,
     /// Device or resource busy
     Busy,
+    /// The filesystem is corrupted and needs to be repaired.
+    FilesystemCorrupted,
+    /// The filesystem or storage medium is read-only.
+    ReadOnlyFilesystem,
+    /// The file is not a valid executable.
+    InvalidExecutable,
+    /// The operation is not supported by the object it is applied to, e.g. an
+    /// `ioctl` on a file that does not implement it.
+    ///
+    /// Unlike [`Unsupported`](AxError::Unsupported), which means that the
+    /// operation is not implemented at all, this is `ENOTSUP`/`EOPNOTSUPP`.
+    NotSupported,
+    /// The operation is not permitted, e.g. it needs a capability the task lacks.
+    OperationNotPermitted,
+    /// The process or thread does not exist.
+    NoSuchProcess,
+    /// The device or address does not exist.
+    NoSuchDeviceOrAddress,
+    /// The argument and environment lists are too long.
+    ArgumentListTooLong,
+    /// The file descriptor is invalid or lacks the required access mode.
+    BadFileDescriptor,
+    /// There are no child processes to wait for.
+    NoChildProcess,
+    /// A block device is required.
+    NotABlockDevice,
+    /// The operation would link or rename across filesystems.
+    CrossesDevices,
+    /// The device does not exist or does not support the operation.
+    NoSuchDevice,
+    /// The system-wide limit on open files is reached.
+    FileTableOverflow,
+    /// The per-process limit on open files is reached.
+    TooManyOpenFiles,
+    /// The file does not support the `ioctl`, e.g. it is not a terminal.
+    NotATty,
+    /// The executable is being written to, or the file is being executed.
+    ExecutableFileBusy,
+    /// The file would exceed the maximum file size.
+    FileTooLarge,
+    /// The file is a pipe, socket or FIFO and cannot seek.
+    NotSeekable,
+    /// The file has too many hard links.
+    TooManyLinks,
+    /// The reading end of a pipe or the peer of a socket has gone away.
+    BrokenPipe,
+    /// The result is out of range, e.g. it does not fit the buffer.
+    OutOfRange,
+    /// The operation would deadlock.
+    Deadlock,
+    /// The path or a component of it is too long.
+    NameTooLong,
+    /// No record locks are available.
+    NoLocksAvailable,
+    /// Too many symbolic links are encountered while resolving the path.
+    FilesystemLoop,
+    /// There is no message of the desired type.
+    NoMessage,
+    /// The IPC identifier is removed.
+    IdentifierRemoved,
+    /// No data is available, e.g. the extended attribute does not exist.
+    NoData,
+    /// A protocol error occurred.
+    ProtocolError,
+    /// The message is malformed.
+    BadMessage,
+    /// The value is too large for the defined data type.
+    Overflow,
+    /// The byte sequence is not valid in the character encoding.
+    IllegalByteSequence,
+    /// The file descriptor is not a socket.
+    NotASocket,
+    /// The socket is not connected and no destination address is given.
+    DestinationAddressRequired,
+    /// The message is too long for the socket.
+    MessageTooLong,
+    /// The protocol is of the wrong type for the socket.
+    WrongProtocolType,
+    /// The socket option is not available at the given level.
+    ProtocolOptionUnavailable,
+    /// The protocol is not supported.
+    ProtocolNotSupported,
+    /// The socket type is not supported.
+    SocketTypeNotSupported,
+    /// The address family is not supported by the protocol.
+    AddressFamilyNotSupported,
+    /// The address is not assigned to a local interface.
+    AddrNotAvailable,
+    /// The network is down.
+    NetworkDown,
+    /// No route to the network is known.
+    NetworkUnreachable,
+    /// The connection was aborted locally.
+    ConnectionAborted,
+    /// No buffer space is available, e.g. the queue of the interface is full.
+    NoBufferSpace,
+    /// The socket is already connected.
+    AlreadyConnected,
+    /// The socket is shut down for sending.
+    EndpointShutdown,
+    /// The connection timed out, e.g. the peer did not respond.
+    ConnectionTimedOut,
+    /// The host is down.
+    HostDown,
+    /// No route to the host is known.
+    HostUnreachable,
+    /// The operation is already in progress, e.g. a non-blocking connect.
+    AlreadyInProgress,
+    /// The operation is in progress and will complete asynchronously.
+    InProgress,
+    /// The file handle of a network filesystem is stale.
+    StaleNetworkFileHandle,
+    /// The disk quota is exceeded.
+    QuotaExceeded,
+    /// No medium is found in the drive.
+    NoMedium,
+    /// The operation was canceled.
+    Canceled,
+    /// The memory page has a hardware error.
+    HardwareMemoryError,
+    /// A driver cannot be probed until a device it depends on is, and is
+    /// probed again later by the device manager.
+    ///
+    /// It is kernel-internal and never returned to user space, where it
+    /// becomes [`WouldBlock`](AxError::WouldBlock) (see
+    /// [`AxError::user_visible`]).
+    ProbeDeferred,
+    /// No STREAMS resources are available, e.g. for a pseudo-terminal
+    /// message.
+    NoStreamResources,
+    /// The device is not a STREAMS device, e.g. for a STREAMS `ioctl`.
+    NotAStream,
+    /// The link to the remote end, e.g. of a remote filesystem mount, has
+    /// been severed.
+    LinkSevered,
+    /// An argument is outside of the domain of a mathematical function.
+    ArgumentOutOfDomain,
+    /// The owner of a robust mutex died while holding it.
+    OwnerDead,
+    /// The state protected by a robust mutex is not recoverable.
+    NotRecoverable,
+    /// The operation is not possible because the radio is blocked by RF-kill.
+    RfKill,
+    /// A shared library or program interpreter is corrupted, e.g. an
+    /// executable's `PT_INTERP` is not a valid ELF image.
+    BadSharedLibrary,
+    /// A message could not be sent over a communication channel, e.g. to
+    /// platform firmware.
+    CommunicationError,
+    /// The remote end of an I/O operation, e.g. a device or firmware, failed.
+    RemoteIo,
 }
 
 /// A specialized [`Result`] type with [`AxError`] as the error type.
@@ -101,41 +473,378 @@ pub type AxResult<T = ()> = Result<T, AxError>;
 /// A specialized [`Result`] type with [`LinuxError`] as the error type.
 pub type LinuxResult<T = ()> = Result<T, LinuxError>;
 
+/// The default log target of the warnings printed by [`ax_err!`] and
+/// [`ax_err_type!`].
+pub const LOG_TARGET: &str = "axerrno";
+
 /// Convenience method to construct an [`AxError`] type while printing a warning
 /// message.
 ///
+/// The warning is logged under the [`LOG_TARGET`] target and is prefixed with
+/// the module path of the caller. Another target can be given with a leading
+/// `target: "..."` argument.
+///
+/// Structured fields can follow the message, or take its place, as
+/// `key = value`, `key = %value` or `key = ?value` to capture the value with
+/// its `ToValue`, `Display` or `Debug` implementation. They are passed to the
+/// key-value API of `log` so that log pipelines can aggregate them.
+///
+/// The message and fields are evaluated lazily, only if the warning is
+/// printed: not at all if warnings are disabled by the log level, or with the
+/// `compact-log` and `deferred-log` features, which do not print them.
+///
 /// # Examples
 ///
 /// ```
 /// # use axerrno::{ax_err_type, AxError};
 /// #
-/// // Also print "[AxError::AlreadyExists]" if the `log` crate is enabled.
+/// // Also print "crate::module: [AxError::AlreadyExists]" if the `log` crate
+/// // is enabled.
 /// assert_eq!(
 ///     ax_err_type!(AlreadyExists),
 ///     AxError::AlreadyExists,
 /// );
 ///
-/// // Also print "[AxError::BadAddress] the address is 0!" if the `log` crate
-/// // is enabled.
+/// // Also print "crate::module: [AxError::BadAddress] the address is 0!" if
+/// // the `log` crate is enabled.
 /// assert_eq!(
 ///     ax_err_type!(BadAddress, "the address is 0!"),
 ///     AxError::BadAddress,
 /// );
+///
+/// // Log under the "mm" target instead of "axerrno".
+/// assert_eq!(
+///     ax_err_type!(target: "mm", NoMemory, "out of frames"),
+///     AxError::NoMemory,
+/// );
+///
+/// // Attach the fields `path` and `fd` to the record.
+/// let path = "/etc/passwd";
+/// assert_eq!(
+///     ax_err_type!(NotFound, "lookup failed", path = %path, fd = 3),
+///     AxError::NotFound,
+/// );
 /// ```
 #[macro_export]
 macro_rules! ax_err_type {
-    ($err: ident) => {{
-        use $crate::AxError::*;
-        $crate::__priv::warn!("[AxError::{:?}]", $err);
-        $err
+    (target: $target: expr, $err: ident $(, $($args: tt)+)?) => {{
+        let location = ::core::panic::Location::caller();
+        $crate::__cold(|| {
+            use $crate::AxError::*;
+            let err = $crate::__new_err!(@at location, $err);
+            $crate::__log_err!(@at location, target: $target, err $(, $($args)+)?);
+            err
+        })
     }};
-    ($err: ident, $msg: expr) => {{
-        use $crate::AxError::*;
-        $crate::__priv::warn!("[AxError::{:?}] {}", $err, $msg);
+    ($err: ident $(, $($args: tt)+)?) => {
+        $crate::ax_err_type!(target: $crate::LOG_TARGET, $err $(, $($args)+)?)
+    };
+}
+
+/// Reports `err` like [`ax_err_type!`] and returns it, from a single
+/// outlined function shared by all callers.
+///
+/// Each use of the error macros instantiates its own out-of-line copy of the
+/// reporting code; `raise` keeps one copy for the whole kernel, at the cost
+/// of logging the caller's location instead of its module and message.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{raise, AxError, AxResult};
+/// #
+/// fn lookup(ino: u64) -> AxResult<u64> {
+///     if ino == 0 {
+///         // Also print "axerrno: [AxError::NotFound] src/main.rs:6:13".
+///         return Err(raise(AxError::NotFound));
+///     }
+///     Ok(ino)
+/// }
+///
+/// assert_eq!(lookup(0), Err(AxError::NotFound));
+/// ```
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn raise(err: AxError) -> AxError {
+    let location = core::panic::Location::caller();
+    let err = __new_err!(@at location, err);
+    __log_err!(@caller location, target: LOG_TARGET, err, location);
+    err
+}
+
+/// Calls `f` out of line.
+///
+/// The error macros move their expansion into the closure, so the code
+/// constructing and logging errors is kept out of the hot path of the
+/// caller, and calls to it are laid out as unlikely.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn __cold<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __new_err {
+    (@at $location: expr, $err: expr) => {
+        $crate::sink::__emit($crate::__inject_err!(@at $location, $err), $location)
+    };
+    ($err: expr) => {
+        $crate::__new_err!(@at ::core::panic::Location::caller(), $err)
+    };
+}
+
+#[cfg(not(feature = "fault-inject"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __inject_err {
+    (@at $location: expr, $err: expr) => {
         $err
+    };
+}
+
+#[cfg(feature = "fault-inject")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __inject_err {
+    (@at $location: expr, $err: expr) => {
+        $crate::inject::__substitute($err, $location)
+    };
+}
+
+/// Returns an error from the function if the fault injection policy forces
+/// one at this site.
+///
+/// Does nothing unless the `fault-inject` feature is enabled. See the
+/// `inject` module.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{fault_point, AxResult};
+/// #
+/// fn alloc_frame() -> AxResult<usize> {
+///     fault_point!();
+///     Ok(0x8000_0000)
+/// }
+///
+/// assert_eq!(alloc_frame(), Ok(0x8000_0000));
+/// ```
+#[cfg(not(feature = "fault-inject"))]
+#[macro_export]
+macro_rules! fault_point {
+    () => {};
+}
+
+/// Returns an error from the function if the fault injection policy forces
+/// one at this site.
+///
+/// See the [`inject`] module.
+#[cfg(feature = "fault-inject")]
+#[macro_export]
+macro_rules! fault_point {
+    () => {
+        $crate::inject::__fault_point(::core::panic::Location::caller())?
+    };
+}
+
+#[cfg(all(
+    feature = "log",
+    not(any(feature = "deferred-log", feature = "compact-log"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_err {
+    (@caller $location: expr, $($rest: tt)+) => {
+        $crate::__log_err!(@at $location, $($rest)+)
+    };
+    (@at $location: expr, target: $target: expr, $err: expr $(, $($args: tt)+)?) => {
+        $crate::__log_err!(target: $target, $err $(, $($args)+)?)
+    };
+    (target: $target: expr, $err: expr) => {
+        $crate::__priv::warn!(
+            target: $target,
+            "{}{}: [AxError::{:?}]",
+            $crate::context::__Prefix,
+            ::core::module_path!(),
+            $err
+        )
+    };
+    (target: $target: expr, $err: expr, $key: ident = $($fields: tt)+) => {
+        $crate::__log_kv!(
+            $target,
+            [],
+            [$key = $($fields)+],
+            "{}{}: [AxError::{:?}]",
+            $crate::context::__Prefix,
+            ::core::module_path!(),
+            $err
+        )
+    };
+    (target: $target: expr, $err: expr, $msg: expr, $($fields: tt)+) => {
+        $crate::__log_kv!(
+            $target,
+            [],
+            [$($fields)+],
+            "{}{}: [AxError::{:?}] {}",
+            $crate::context::__Prefix,
+            ::core::module_path!(),
+            $err,
+            $msg
+        )
+    };
+    (target: $target: expr, $err: expr, $msg: expr) => {
+        $crate::__priv::warn!(
+            target: $target,
+            "{}{}: [AxError::{:?}] {}",
+            $crate::context::__Prefix,
+            ::core::module_path!(),
+            $err,
+            $msg
+        )
+    };
+    (suppressed: $target: expr, $missed: expr) => {
+        $crate::__priv::warn!(
+            target: $target,
+            "{}: {} warnings suppressed",
+            ::core::module_path!(),
+            $missed
+        )
+    };
+}
+
+#[cfg(all(feature = "compact-log", not(feature = "deferred-log")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_err {
+    (@at $location: expr, target: $target: expr, $err: expr $(, $($args: tt)+)?) => {
+        $crate::__log_err!(target: $target, $err $(, $($args)+)?)
+    };
+    (@caller $location: expr, target: $target: expr, $err: expr $(, $($args: tt)+)?) => {{
+        let location: &::core::panic::Location<'_> = $location;
+        $crate::__discard!($($($args)+)?);
+        $crate::__priv::warn!(
+            target: $target,
+            "site {:08x}: errno {}",
+            $crate::site::site_id(location.file(), location.line(), location.column()),
+            $crate::LinuxError::from($err).code()
+        )
+    }};
+    (target: $target: expr, $err: expr $(, $($args: tt)+)?) => {{
+        const FILE: &str = ::core::file!();
+        #[used]
+        #[link_section = "axerrno_sites"]
+        static SITE: $crate::site::SiteRecord<{ FILE.len().next_multiple_of(4) }> =
+            $crate::site::SiteRecord::new(FILE, ::core::line!(), ::core::column!());
+        $crate::__discard!($($($args)+)?);
+        $crate::__priv::warn!(
+            target: $target,
+            "site {:08x}: errno {}",
+            $crate::site::site_id(FILE, ::core::line!(), ::core::column!()),
+            $crate::LinuxError::from($err).code()
+        )
+    }};
+    (suppressed: $target: expr, $missed: expr) => {
+        $crate::__priv::warn!(target: $target, "{} suppressed", $missed)
+    };
+}
+
+#[cfg(feature = "deferred-log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_err {
+    (@caller $location: expr, $($rest: tt)+) => {
+        $crate::__log_err!(@at $location, $($rest)+)
+    };
+    (@at $location: expr, target: $target: expr, $err: expr $(, $($args: tt)+)?) => {{
+        let _ = $target;
+        $crate::__discard!($($($args)+)?);
+        $crate::deferred::record($err, $location)
+    }};
+    (target: $target: expr, $err: expr $(, $($args: tt)+)?) => {
+        $crate::__log_err!(
+            @at ::core::panic::Location::caller(),
+            target: $target,
+            $err $(, $($args)+)?
+        )
+    };
+    (suppressed: $target: expr, $missed: expr) => {{
+        let _ = ($target, $missed);
+    }};
+}
+
+#[cfg(not(any(feature = "log", feature = "deferred-log")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_err {
+    (@caller $location: expr, $($rest: tt)+) => {
+        $crate::__log_err!(@at $location, $($rest)+)
+    };
+    (@at $location: expr, target: $target: expr, $err: expr $(, $($args: tt)+)?) => {
+        $crate::__log_err!(target: $target, $err $(, $($args)+)?)
+    };
+    (target: $target: expr, $err: expr $(, $($args: tt)+)?) => {{
+        let _ = ($target, $err);
+        $crate::__discard!($($($args)+)?);
+    }};
+    (suppressed: $target: expr, $missed: expr) => {{
+        let _ = ($target, $missed);
     }};
 }
 
+/// Logs a warning with the structured fields in the second bracket, given in
+/// the `key = value`, `key = %display` or `key = ?debug` form.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_kv {
+    ($target: expr, [$($out: tt)*], [$key: ident = % $value: expr $(, $($rest: tt)*)?], $($args: tt)+) => {
+        $crate::__log_kv!($target, [$($out)* ($key:% = $value)], [$($($rest)*)?], $($args)+)
+    };
+    ($target: expr, [$($out: tt)*], [$key: ident = ? $value: expr $(, $($rest: tt)*)?], $($args: tt)+) => {
+        $crate::__log_kv!($target, [$($out)* ($key:? = $value)], [$($($rest)*)?], $($args)+)
+    };
+    ($target: expr, [$($out: tt)*], [$key: ident = $value: expr $(, $($rest: tt)*)?], $($args: tt)+) => {
+        $crate::__log_kv!($target, [$($out)* ($key = $value)], [$($($rest)*)?], $($args)+)
+    };
+    ($target: expr, [$(($($kv: tt)*))+], [], $($args: tt)+) => {
+        $crate::__priv::warn!(target: $target, $($($kv)*),+; $($args)+)
+    };
+}
+
+/// Uses the message and fields of an error macro without evaluating them, so
+/// that the variables they capture are not reported as unused.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __discard {
+    () => {};
+    ($key: ident = % $value: expr $(, $($rest: tt)*)?) => {
+        let _ = || {
+            let _ = &$value;
+        };
+        $crate::__discard!($($($rest)*)?)
+    };
+    ($key: ident = ? $value: expr $(, $($rest: tt)*)?) => {
+        let _ = || {
+            let _ = &$value;
+        };
+        $crate::__discard!($($($rest)*)?)
+    };
+    ($key: ident = $value: expr $(, $($rest: tt)*)?) => {
+        let _ = || {
+            let _ = &$value;
+        };
+        $crate::__discard!($($($rest)*)?)
+    };
+    ($msg: expr $(, $($rest: tt)*)?) => {
+        let _ = || {
+            let _ = &$msg;
+        };
+        $crate::__discard!($($($rest)*)?)
+    };
+}
+
 /// Ensure a condition is true. If it is not, return from the function
 /// with an error.
 ///
@@ -160,34 +869,498 @@ macro_rules! ensure {
     };
 }
 
+/// Ensure two expressions are equal (using [`PartialEq`]). If they are not,
+/// return from the function with [`ax_err!`], printing both values (using
+/// [`Debug`]) in the warning.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use axerrno::{ensure_eq, AxError, AxResult};
+///
+/// fn check_magic(magic: u32) -> AxResult {
+///     ensure_eq!(magic, 0xEF53, InvalidData, "bad superblock magic");
+///     Ok(())
+/// }
+///
+/// assert_eq!(check_magic(0xEF53), Ok(()));
+/// assert_eq!(check_magic(0), Err(AxError::InvalidData));
+/// ```
+///
+/// [`Debug`]: core::fmt::Debug
+#[macro_export]
+macro_rules! ensure_eq {
+    ($left: expr, $right: expr, $err: ident $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    return $crate::ax_err!(
+                        $err,
+                        ::core::format_args!("`{:?}` != `{:?}`", left, right)
+                    );
+                }
+            }
+        }
+    };
+    ($left: expr, $right: expr, $err: ident, $msg: expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    return $crate::ax_err!(
+                        $err,
+                        ::core::format_args!("{}: `{:?}` != `{:?}`", $msg, left, right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Ensure two expressions are not equal (using [`PartialEq`]). If they are,
+/// return from the function with [`ax_err!`], printing both values (using
+/// [`Debug`]) in the warning.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use axerrno::{ensure_ne, AxError, AxResult};
+///
+/// fn close(fd: i32) -> AxResult {
+///     ensure_ne!(fd, -1, InvalidInput);
+///     Ok(())
+/// }
+///
+/// assert_eq!(close(-1), Err(AxError::InvalidInput));
+/// ```
+///
+/// [`Debug`]: core::fmt::Debug
+#[macro_export]
+macro_rules! ensure_ne {
+    ($left: expr, $right: expr, $err: ident $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    return $crate::ax_err!(
+                        $err,
+                        ::core::format_args!("`{:?}` == `{:?}`", left, right)
+                    );
+                }
+            }
+        }
+    };
+    ($left: expr, $right: expr, $err: ident, $msg: expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    return $crate::ax_err!(
+                        $err,
+                        ::core::format_args!("{}: `{:?}` == `{:?}`", $msg, left, right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Return from the function with [`ax_err!`] if a condition is true.
+///
+/// The arguments after the condition are the same as [`ax_err!`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use axerrno::{bail_if, AxError, AxResult};
+///
+/// fn read(buf: &mut [u8]) -> AxResult<usize> {
+///     bail_if!(buf.is_empty(), InvalidInput, "empty buffer");
+///     Ok(buf.len())
+/// }
+///
+/// assert_eq!(read(&mut []), Err(AxError::InvalidInput));
+/// ```
+#[macro_export]
+macro_rules! bail_if {
+    ($cond: expr, $($args: tt)+) => {
+        if $cond {
+            return $crate::ax_err!($($args)+);
+        }
+    };
+}
+
+/// Matches an error against a declared set of codes, failing to compile
+/// unless the arms cover exactly that set.
+///
+/// The first argument is the error, followed by its type, [`AxError`] or
+/// [`LinuxError`], and the declared set. The arms name codes of the set
+/// without the type prefix, and a final `_` arm handles all other codes.
+/// Adding a code to the set without handling it, or handling a code outside
+/// of it, is a compile error, which restores the exhaustiveness checks that
+/// `#[non_exhaustive]` takes away.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{errno_match, AxError};
+/// #
+/// fn retry_delay(err: AxError) -> Option<u32> {
+///     errno_match!(err, AxError [WouldBlock, Again, Timeout, Interrupted] {
+///         WouldBlock | Again => Some(1),
+///         Timeout => Some(100),
+///         Interrupted => Some(0),
+///         _ => None,
+///     })
+/// }
+///
+/// assert_eq!(retry_delay(AxError::Timeout), Some(100));
+/// assert_eq!(retry_delay(AxError::NotFound), None);
+/// ```
+///
+/// Forgetting a declared code does not compile:
+///
+/// ```compile_fail
+/// # use axerrno::{errno_match, LinuxError};
+/// #
+/// fn is_missing(err: LinuxError) -> bool {
+///     errno_match!(err, LinuxError [ENOENT, ENOTDIR] {
+///         ENOENT => true,
+///         _ => false,
+///     })
+/// }
+/// ```
+#[macro_export]
+macro_rules! errno_match {
+    ($err: expr, $ty: ident [$($declared: ident),+ $(,)?] {
+        $($($code: ident)|+ => $arm: expr,)+
+        _ => $fallback: expr $(,)?
+    }) => {{
+        #[allow(non_camel_case_types, dead_code)]
+        enum Declared {
+            $($declared,)+
+        }
+        const _: fn(Declared) = |declared| match declared {
+            $($(Declared::$code)|+ => {})+
+        };
+        match $err {
+            $($($crate::$ty::$code)|+ => $arm,)+
+            _ => $fallback,
+        }
+    }};
+}
+
+/// Defines marker types for [`set::Errnos`], naming sets of [`LinuxError`]s.
+///
+/// See [`set::OneOf`] for an example.
+#[macro_export]
+macro_rules! errno_set {
+    ($($(#[$attr: meta])* $vis: vis $name: ident = [$($code: ident),* $(,)?];)+) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug)]
+            $vis enum $name {}
+
+            impl $crate::set::Errnos for $name {
+                const SET: $crate::set::ErrorSet =
+                    $crate::set::ErrorSet::new(&[$($crate::LinuxError::$code),*]);
+            }
+        )+
+    };
+}
+
+/// Constructs a [`set::OneOf`] of the set `$set`, checking at compile time
+/// that the set contains the error.
+///
+/// See [`set::OneOf`] for an example.
+#[macro_export]
+macro_rules! one_of {
+    ($set: ty, $code: ident) => {
+        const { $crate::set::OneOf::<$set>::new_checked($crate::LinuxError::$code) }
+    };
+}
+
 /// Convenience method to construct an [`Err(AxError)`] type while printing a
 /// warning message.
 ///
+/// Accepts the same arguments as [`ax_err_type!`].
+///
 /// # Examples
 ///
 /// ```
 /// # use axerrno::{ax_err, AxResult, AxError};
 /// #
-/// // Also print "[AxError::AlreadyExists]" if the `log` crate is enabled.
+/// // Also print "crate::module: [AxError::AlreadyExists]" if the `log` crate
+/// // is enabled.
 /// assert_eq!(
 ///     ax_err!(AlreadyExists),
 ///     AxResult::<()>::Err(AxError::AlreadyExists),
 /// );
 ///
-/// // Also print "[AxError::BadAddress] the address is 0!" if the `log` crate is enabled.
+/// // Also print "crate::module: [AxError::BadAddress] the address is 0!" if
+/// // the `log` crate is enabled.
 /// assert_eq!(
 ///     ax_err!(BadAddress, "the address is 0!"),
 ///     AxResult::<()>::Err(AxError::BadAddress),
 /// );
+///
+/// // Log under the "net" target instead of "axerrno".
+/// assert_eq!(
+///     ax_err!(target: "net", WouldBlock),
+///     AxResult::<()>::Err(AxError::WouldBlock),
+/// );
+///
+/// // Log the fields without a message.
+/// let fd = 3;
+/// assert_eq!(
+///     ax_err!(NotConnected, fd = fd, peer = ?("10.0.0.1", 80)),
+///     AxResult::<()>::Err(AxError::NotConnected),
+/// );
 /// ```
 /// [`Err(AxError)`]: Err
 #[macro_export]
 macro_rules! ax_err {
-    ($err: ident) => {
-        Err($crate::ax_err_type!($err))
+    (target: $target: expr, $err: ident $(, $($args: tt)+)?) => {
+        Err($crate::ax_err_type!(target: $target, $err $(, $($args)+)?))
+    };
+    ($err: ident $(, $($args: tt)+)?) => {
+        Err($crate::ax_err_type!($err $(, $($args)+)?))
+    };
+}
+
+/// Unwraps a [`Result`] whose error converts into an [`AxError`], or converts
+/// the error, prints a warning like [`ax_err!`] and returns it from the
+/// function.
+///
+/// It works like `?`, with the error also going through the logging, hooks
+/// and sinks of [`ax_err!`], so it shows where foreign errors enter. The
+/// arguments after the result are the same as [`ax_err!`]. The returned error
+/// is converted with [`From`], so the function may return any error type
+/// an [`AxError`] converts into.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_try, AxError, AxResult, LinuxError, LinuxResult};
+/// #
+/// fn read_sector(ok: bool) -> Result<u8, AxError> {
+///     if ok { Ok(7) } else { Err(AxError::Io) }
+/// }
+///
+/// fn mount(ok: bool) -> LinuxResult<u8> {
+///     // Print "crate::module: [AxError::Io] reading the superblock" on error.
+///     let magic = ax_try!(read_sector(ok), "reading the superblock");
+///     Ok(magic + 1)
+/// }
+///
+/// assert_eq!(mount(true), Ok(8));
+/// assert_eq!(mount(false), Err(LinuxError::EIO));
+/// ```
+#[macro_export]
+macro_rules! ax_try {
+    (target: $target: expr, $res: expr $(, $($args: tt)+)?) => {
+        match $res {
+            Ok(v) => v,
+            Err(e) => {
+                let location = ::core::panic::Location::caller();
+                let err = $crate::__cold(|| {
+                    let err = ::core::convert::Into::<$crate::AxError>::into(e);
+                    let err = $crate::__new_err!(@at location, err);
+                    $crate::__log_err!(@at location, target: $target, err $(, $($args)+)?);
+                    err
+                });
+                return Err(::core::convert::From::from(err));
+            }
+        }
+    };
+    ($res: expr $(, $($args: tt)+)?) => {
+        $crate::ax_try!(target: $crate::LOG_TARGET, $res $(, $($args)+)?)
+    };
+}
+
+/// Unwraps an [`AxResult`] whose failure would be a kernel bug, panicking
+/// with the error name, its errno name and message otherwise.
+///
+/// Unlike [`Result::unwrap`], the panic message keeps the meaning of the
+/// error, e.g. `superblock: AxError::NotFound (ENOENT): Entity not found`.
+/// Like `panic!`, the location is that of the caller.
+///
+/// # Examples
+///
+/// ```should_panic
+/// # use axerrno::{expect_ok, AxError, AxResult};
+/// #
+/// let res: AxResult<u32> = Ok(1);
+/// assert_eq!(expect_ok!(res), 1);
+///
+/// let res: AxResult<u32> = Err(AxError::NotFound);
+/// // Panics with "root inode: AxError::NotFound (ENOENT): Entity not found".
+/// expect_ok!(res, "root inode");
+/// ```
+#[macro_export]
+macro_rules! expect_ok {
+    ($res: expr $(,)?) => {
+        match $res {
+            Ok(v) => v,
+            Err(e) => $crate::__expect_failed(e, None),
+        }
+    };
+    ($res: expr, $($msg: tt)+) => {
+        match $res {
+            Ok(v) => v,
+            Err(e) => $crate::__expect_failed(e, Some(::core::format_args!($($msg)+))),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn __expect_failed(err: AxError, msg: Option<fmt::Arguments>) -> ! {
+    let errno = LinuxError::from(err);
+    match msg {
+        Some(msg) => panic!("{msg}: AxError::{err:?} ({errno:?}): {err}"),
+        None => panic!("AxError::{err:?} ({errno:?}): {err}"),
+    }
+}
+
+/// Convert an [`Option`] into an [`AxResult`], printing a warning with
+/// [`ax_err!`] if it is `None`.
+///
+/// The arguments after the option are the same as [`ax_err!`].
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ok_or_ax, AxError, AxResult};
+/// #
+/// let slots = [Some(1), None];
+/// assert_eq!(ok_or_ax!(slots[0], NotFound), Ok(1));
+/// // Also print "crate::module: [AxError::NotFound] empty slot" if the `log`
+/// // crate is enabled.
+/// let res: AxResult<i32> = ok_or_ax!(slots[1], NotFound, "empty slot");
+/// assert_eq!(res, Err(AxError::NotFound));
+/// ```
+#[macro_export]
+macro_rules! ok_or_ax {
+    ($option: expr, $($args: tt)+) => {
+        match $option {
+            Some(v) => Ok(v),
+            None => $crate::ax_err!($($args)+),
+        }
+    };
+}
+
+/// Like [`ax_err!`], but prints the warning only the first time this call
+/// site is reached.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_err_once, AxResult, AxError};
+/// #
+/// for _ in 0..3 {
+///     // Print "crate::module: [AxError::Unsupported] no FPU" only once.
+///     let res: AxResult = ax_err_once!(Unsupported, "no FPU");
+///     assert_eq!(res, Err(AxError::Unsupported));
+/// }
+/// ```
+#[macro_export]
+macro_rules! ax_err_once {
+    (target: $target: expr, $err: ident $(, $($args: tt)+)?) => {{
+        static ONCE: $crate::__priv::AtomicBool = $crate::__priv::AtomicBool::new(false);
+        let location = ::core::panic::Location::caller();
+        Err($crate::__cold(|| {
+            let err = $crate::__new_err!(@at location, $crate::AxError::$err);
+            if !ONCE.swap(true, $crate::__priv::Ordering::Relaxed) {
+                $crate::__log_err!(@at location, target: $target, err $(, $($args)+)?);
+            }
+            err
+        }))
+    }};
+    ($err: ident $(, $($args: tt)+)?) => {
+        $crate::ax_err_once!(target: $crate::LOG_TARGET, $err $(, $($args)+)?)
+    };
+}
+
+/// Like [`ax_err!`], but prints at most [`DEFAULT_BURST`] warnings per
+/// [`DEFAULT_INTERVAL`] from this call site, like Linux's
+/// `printk_ratelimited`.
+///
+/// The number of suppressed warnings is printed with the next warning that
+/// passes. See [`RateLimit`] for how time is measured.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_err_ratelimited, AxResult, AxError};
+/// #
+/// for _ in 0..100 {
+///     // Print the warning at most 10 times per 5 seconds.
+///     let res: AxResult = ax_err_ratelimited!(Io, "device timeout");
+///     assert_eq!(res, Err(AxError::Io));
+/// }
+/// ```
+///
+/// [`DEFAULT_BURST`]: ratelimit::DEFAULT_BURST
+/// [`DEFAULT_INTERVAL`]: ratelimit::DEFAULT_INTERVAL
+/// [`RateLimit`]: ratelimit::RateLimit
+#[macro_export]
+macro_rules! ax_err_ratelimited {
+    (target: $target: expr, $err: ident $(, $($args: tt)+)?) => {{
+        static LIMIT: $crate::ratelimit::RateLimit = $crate::ratelimit::RateLimit::new(
+            $crate::ratelimit::DEFAULT_INTERVAL,
+            $crate::ratelimit::DEFAULT_BURST,
+        );
+        let location = ::core::panic::Location::caller();
+        Err($crate::__cold(|| {
+            let err = $crate::__new_err!(@at location, $crate::AxError::$err);
+            if let Some(missed) = LIMIT.check() {
+                if missed > 0 {
+                    $crate::__log_err!(suppressed: $target, missed);
+                }
+                $crate::__log_err!(@at location, target: $target, err $(, $($args)+)?);
+            }
+            err
+        }))
+    }};
+    ($err: ident $(, $($args: tt)+)?) => {
+        $crate::ax_err_ratelimited!(target: $crate::LOG_TARGET, $err $(, $($args)+)?)
+    };
+}
+
+/// Converts the result of a syscall handler into its [`SyscallResult`].
+///
+/// The handler result is an `AxResult<usize>`, a `LinuxResult<usize>` or any
+/// `Result<usize, E>` with `E: Into<LinuxError>`. On failure, the error is
+/// logged at debug level with the syscall name, unless `quiet` is given, and
+/// kernel-internal codes are replaced with
+/// [`user_visible`](syscall::user_visible). With the `stats` feature, the
+/// returned code is counted in [`stats`].
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{syscall_result, AxError, AxResult};
+/// #
+/// fn sys_close(fd: i32) -> AxResult<usize> {
+///     if fd < 0 {
+///         return Err(AxError::BadFileDescriptor);
+///     }
+///     Ok(0)
+/// }
+///
+/// assert_eq!(syscall_result!(close, sys_close(-1)).raw(), -9);
+/// // Expected failures, e.g. of non-blocking reads, need not be logged.
+/// let res: AxResult<usize> = Err(AxError::WouldBlock);
+/// assert_eq!(syscall_result!(read, quiet, res).raw(), -11);
+/// ```
+///
+/// [`SyscallResult`]: syscall::SyscallResult
+#[macro_export]
+macro_rules! syscall_result {
+    ($name: ident, quiet, $res: expr) => {
+        $crate::syscall::__epilogue(stringify!($name), $res, false)
     };
-    ($err: ident, $msg: expr) => {
-        Err($crate::ax_err_type!($err, $msg))
+    ($name: ident, $res: expr) => {
+        $crate::syscall::__epilogue(stringify!($name), $res, true)
     };
 }
 
@@ -222,6 +1395,75 @@ impl AxError {
             Timeout => "Timeout",
             Again => "Try Again",
             Busy => "Device or resource busy",
+            FilesystemCorrupted => "Filesystem corrupted",
+            ReadOnlyFilesystem => "Read-only filesystem",
+            InvalidExecutable => "Invalid executable",
+            NotSupported => "Operation not supported by the object",
+            OperationNotPermitted => "Operation not permitted",
+            NoSuchProcess => "No such process",
+            NoSuchDeviceOrAddress => "No such device or address",
+            ArgumentListTooLong => "Argument list too long",
+            BadFileDescriptor => "Bad file descriptor",
+            NoChildProcess => "No child processes",
+            NotABlockDevice => "Block device required",
+            CrossesDevices => "Cross-device link",
+            NoSuchDevice => "No such device",
+            FileTableOverflow => "File table overflow",
+            TooManyOpenFiles => "Too many open files",
+            NotATty => "Not a typewriter",
+            ExecutableFileBusy => "Text file busy",
+            FileTooLarge => "File too large",
+            NotSeekable => "Illegal seek",
+            TooManyLinks => "Too many links",
+            BrokenPipe => "Broken pipe",
+            OutOfRange => "Result out of range",
+            Deadlock => "Resource deadlock would occur",
+            NameTooLong => "File name too long",
+            NoLocksAvailable => "No record locks available",
+            FilesystemLoop => "Too many symbolic links encountered",
+            NoMessage => "No message of desired type",
+            IdentifierRemoved => "Identifier removed",
+            NoData => "No data available",
+            ProtocolError => "Protocol error",
+            BadMessage => "Bad message",
+            Overflow => "Value too large for defined data type",
+            IllegalByteSequence => "Illegal byte sequence",
+            NotASocket => "Socket operation on non-socket",
+            DestinationAddressRequired => "Destination address required",
+            MessageTooLong => "Message too long",
+            WrongProtocolType => "Protocol wrong type for socket",
+            ProtocolOptionUnavailable => "Protocol not available",
+            ProtocolNotSupported => "Protocol not supported",
+            SocketTypeNotSupported => "Socket type not supported",
+            AddressFamilyNotSupported => "Address family not supported by protocol",
+            AddrNotAvailable => "Cannot assign requested address",
+            NetworkDown => "Network is down",
+            NetworkUnreachable => "Network is unreachable",
+            ConnectionAborted => "Software caused connection abort",
+            NoBufferSpace => "No buffer space available",
+            AlreadyConnected => "Transport endpoint is already connected",
+            EndpointShutdown => "Cannot send after transport endpoint shutdown",
+            ConnectionTimedOut => "Connection timed out",
+            HostDown => "Host is down",
+            HostUnreachable => "No route to host",
+            AlreadyInProgress => "Operation already in progress",
+            InProgress => "Operation now in progress",
+            StaleNetworkFileHandle => "Stale file handle",
+            QuotaExceeded => "Quota exceeded",
+            NoMedium => "No medium found",
+            Canceled => "Operation canceled",
+            HardwareMemoryError => "Memory page has hardware error",
+            ProbeDeferred => "Driver requests probe retry",
+            NoStreamResources => "Out of streams resources",
+            NotAStream => "Device not a stream",
+            LinkSevered => "Link has been severed",
+            ArgumentOutOfDomain => "Argument out of domain",
+            OwnerDead => "Owner died",
+            NotRecoverable => "State not recoverable",
+            RfKill => "Operation not possible due to RF-kill",
+            BadSharedLibrary => "Accessing a corrupted shared library",
+            CommunicationError => "Communication error on send",
+            RemoteIo => "Remote I/O error",
         }
     }
 
@@ -231,13 +1473,142 @@ impl AxError {
     }
 }
 
+macro_rules! predicates {
+    ($($variant: ident => $name: ident),* $(,)?) => {
+        impl AxError {
+            $(
+                #[doc = concat!(
+                    "Returns `true` if the error is [`", stringify!($variant),
+                    "`](AxError::", stringify!($variant), ")."
+                )]
+                #[inline]
+                pub const fn $name(&self) -> bool {
+                    matches!(self, AxError::$variant)
+                }
+            )*
+        }
+
+        impl LinuxError {
+            $(
+                #[doc = concat!(
+                    "Returns `true` if the error is the one [`AxError::",
+                    stringify!($variant), "`] converts to.\n\n",
+                    "Several [`AxError`]s may convert to the same [`LinuxError`]."
+                )]
+                #[inline]
+                pub fn $name(&self) -> bool {
+                    *self == AxError::$variant
+                }
+            )*
+        }
+    };
+}
+
+predicates! {
+    AddrInUse => is_addr_in_use,
+    AlreadyExists => is_already_exists,
+    BadAddress => is_bad_address,
+    BadState => is_bad_state,
+    ConnectionRefused => is_connection_refused,
+    ConnectionReset => is_connection_reset,
+    DirectoryNotEmpty => is_directory_not_empty,
+    InvalidData => is_invalid_data,
+    InvalidInput => is_invalid_input,
+    Io => is_io,
+    IsADirectory => is_a_directory,
+    NoMemory => is_no_memory,
+    NotADirectory => is_not_a_directory,
+    NotConnected => is_not_connected,
+    NotFound => is_not_found,
+    PermissionDenied => is_permission_denied,
+    ResourceBusy => is_resource_busy,
+    StorageFull => is_storage_full,
+    UnexpectedEof => is_unexpected_eof,
+    Unsupported => is_unsupported,
+    WouldBlock => is_would_block,
+    WriteZero => is_write_zero,
+    Interrupted => is_interrupted,
+    Timeout => is_timeout,
+    Again => is_again,
+    Busy => is_busy,
+    FilesystemCorrupted => is_filesystem_corrupted,
+    ReadOnlyFilesystem => is_read_only_filesystem,
+    InvalidExecutable => is_invalid_executable,
+    NotSupported => is_not_supported,
+    OperationNotPermitted => is_operation_not_permitted,
+    NoSuchProcess => is_no_such_process,
+    NoSuchDeviceOrAddress => is_no_such_device_or_address,
+    ArgumentListTooLong => is_argument_list_too_long,
+    BadFileDescriptor => is_bad_file_descriptor,
+    NoChildProcess => is_no_child_process,
+    NotABlockDevice => is_not_a_block_device,
+    CrossesDevices => is_crosses_devices,
+    NoSuchDevice => is_no_such_device,
+    FileTableOverflow => is_file_table_overflow,
+    TooManyOpenFiles => is_too_many_open_files,
+    NotATty => is_not_a_tty,
+    ExecutableFileBusy => is_executable_file_busy,
+    FileTooLarge => is_file_too_large,
+    NotSeekable => is_not_seekable,
+    TooManyLinks => is_too_many_links,
+    BrokenPipe => is_broken_pipe,
+    OutOfRange => is_out_of_range,
+    Deadlock => is_deadlock,
+    NameTooLong => is_name_too_long,
+    NoLocksAvailable => is_no_locks_available,
+    FilesystemLoop => is_filesystem_loop,
+    NoMessage => is_no_message,
+    IdentifierRemoved => is_identifier_removed,
+    NoData => is_no_data,
+    ProtocolError => is_protocol_error,
+    BadMessage => is_bad_message,
+    Overflow => is_overflow,
+    IllegalByteSequence => is_illegal_byte_sequence,
+    NotASocket => is_not_a_socket,
+    DestinationAddressRequired => is_destination_address_required,
+    MessageTooLong => is_message_too_long,
+    WrongProtocolType => is_wrong_protocol_type,
+    ProtocolOptionUnavailable => is_protocol_option_unavailable,
+    ProtocolNotSupported => is_protocol_not_supported,
+    SocketTypeNotSupported => is_socket_type_not_supported,
+    AddressFamilyNotSupported => is_address_family_not_supported,
+    AddrNotAvailable => is_addr_not_available,
+    NetworkDown => is_network_down,
+    NetworkUnreachable => is_network_unreachable,
+    ConnectionAborted => is_connection_aborted,
+    NoBufferSpace => is_no_buffer_space,
+    AlreadyConnected => is_already_connected,
+    EndpointShutdown => is_endpoint_shutdown,
+    ConnectionTimedOut => is_connection_timed_out,
+    HostDown => is_host_down,
+    HostUnreachable => is_host_unreachable,
+    AlreadyInProgress => is_already_in_progress,
+    InProgress => is_in_progress,
+    StaleNetworkFileHandle => is_stale_network_file_handle,
+    QuotaExceeded => is_quota_exceeded,
+    NoMedium => is_no_medium,
+    Canceled => is_canceled,
+    HardwareMemoryError => is_hardware_memory_error,
+    ProbeDeferred => is_probe_deferred,
+    NoStreamResources => is_no_stream_resources,
+    NotAStream => is_not_a_stream,
+    LinkSevered => is_link_severed,
+    ArgumentOutOfDomain => is_argument_out_of_domain,
+    OwnerDead => is_owner_dead,
+    NotRecoverable => is_not_recoverable,
+    RfKill => is_rf_kill,
+    BadSharedLibrary => is_bad_shared_library,
+    CommunicationError => is_communication_error,
+    RemoteIo => is_remote_io,
+}
+
 impl TryFrom<i32> for AxError {
     type Error = i32;
 
     #[inline]
     fn try_from(value: i32) -> Result<Self, Self::Error> {
         if value > 0 && value <= core::mem::variant_count::<AxError>() as i32 {
-            Ok(unsafe { core::mem::transmute(value) })
+            Ok(unsafe { core::mem::transmute::<i32, AxError>(value) })
         } else {
             Err(value)
         }
@@ -246,69 +1617,416 @@ impl TryFrom<i32> for AxError {
 
 impl fmt::Display for AxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        f.write_str(self.message())
     }
 }
 
-impl From<AxError> for LinuxError {
-    fn from(e: AxError) -> Self {
-        use AxError::*;
-        match e {
-            AddrInUse => LinuxError::EADDRINUSE,
-            AlreadyExists => LinuxError::EEXIST,
-            BadAddress | BadState => LinuxError::EFAULT,
-            ConnectionRefused => LinuxError::ECONNREFUSED,
-            ConnectionReset => LinuxError::ECONNRESET,
-            DirectoryNotEmpty => LinuxError::ENOTEMPTY,
-            InvalidInput | InvalidData => LinuxError::EINVAL,
-            Io => LinuxError::EIO,
-            IsADirectory => LinuxError::EISDIR,
-            NoMemory => LinuxError::ENOMEM,
-            NotADirectory => LinuxError::ENOTDIR,
-            NotConnected => LinuxError::ENOTCONN,
-            NotFound => LinuxError::ENOENT,
-            PermissionDenied => LinuxError::EACCES,
-            ResourceBusy => LinuxError::EBUSY,
-            StorageFull => LinuxError::ENOSPC,
-            Unsupported => LinuxError::ENOSYS,
-            UnexpectedEof | WriteZero => LinuxError::EIO,
-            WouldBlock => LinuxError::EAGAIN,
-            Interrupted => LinuxError::EINTR,
-            Timeout => LinuxError::ETIME,
-            Again => LinuxError::EAGAIN,
-            Busy => LinuxError::EBUSY,
+/// Defines the conversions between [`AxError`] and [`LinuxError`] from a
+/// single table, so they cannot drift apart.
+///
+/// Each [`LinuxError`] may appear in `canonical` only once, as the inverse
+/// conversion maps it back to that [`AxError`]. The other [`AxError`]s
+/// mapping to the same code are listed in `lossy`.
+macro_rules! linux_mapping {
+    (
+        canonical { $($err: ident => $errno: ident,)* }
+        lossy { $($lossy: ident => $lossy_errno: ident,)* }
+    ) => {
+        impl From<AxError> for LinuxError {
+            fn from(e: AxError) -> Self {
+                match e {
+                    $(AxError::$err => LinuxError::$errno,)*
+                    $(AxError::$lossy => LinuxError::$lossy_errno,)*
+                }
+            }
         }
+
+        /// The inverse of `From<AxError> for LinuxError`.
+        ///
+        /// Where several [`AxError`]s map to the same code, the most general one
+        /// is chosen, e.g. [`EINVAL`](LinuxError::EINVAL) becomes
+        /// [`InvalidInput`](AxError::InvalidInput). Codes no [`AxError`] maps to
+        /// are returned as the error.
+        impl TryFrom<LinuxError> for AxError {
+            type Error = LinuxError;
+
+            #[deny(unreachable_patterns)]
+            fn try_from(e: LinuxError) -> Result<Self, Self::Error> {
+                Ok(match e {
+                    $(LinuxError::$errno => AxError::$err,)*
+                    _ => return Err(e),
+                })
+            }
+        }
+    };
+}
+
+linux_mapping! {
+    canonical {
+        AddrInUse => EADDRINUSE,
+        AlreadyExists => EEXIST,
+        BadAddress => EFAULT,
+        ConnectionRefused => ECONNREFUSED,
+        ConnectionReset => ECONNRESET,
+        DirectoryNotEmpty => ENOTEMPTY,
+        InvalidInput => EINVAL,
+        Io => EIO,
+        IsADirectory => EISDIR,
+        NoMemory => ENOMEM,
+        NotADirectory => ENOTDIR,
+        NotConnected => ENOTCONN,
+        NotFound => ENOENT,
+        PermissionDenied => EACCES,
+        ResourceBusy => EBUSY,
+        StorageFull => ENOSPC,
+        Unsupported => ENOSYS,
+        WouldBlock => EAGAIN,
+        Interrupted => EINTR,
+        Timeout => ETIME,
+        FilesystemCorrupted => EUCLEAN,
+        ReadOnlyFilesystem => EROFS,
+        InvalidExecutable => ENOEXEC,
+        NotSupported => EOPNOTSUPP,
+        OperationNotPermitted => EPERM,
+        NoSuchProcess => ESRCH,
+        NoSuchDeviceOrAddress => ENXIO,
+        ArgumentListTooLong => E2BIG,
+        BadFileDescriptor => EBADF,
+        NoChildProcess => ECHILD,
+        NotABlockDevice => ENOTBLK,
+        CrossesDevices => EXDEV,
+        NoSuchDevice => ENODEV,
+        FileTableOverflow => ENFILE,
+        TooManyOpenFiles => EMFILE,
+        NotATty => ENOTTY,
+        ExecutableFileBusy => ETXTBSY,
+        FileTooLarge => EFBIG,
+        NotSeekable => ESPIPE,
+        TooManyLinks => EMLINK,
+        BrokenPipe => EPIPE,
+        OutOfRange => ERANGE,
+        Deadlock => EDEADLK,
+        NameTooLong => ENAMETOOLONG,
+        NoLocksAvailable => ENOLCK,
+        FilesystemLoop => ELOOP,
+        NoMessage => ENOMSG,
+        IdentifierRemoved => EIDRM,
+        NoData => ENODATA,
+        ProtocolError => EPROTO,
+        BadMessage => EBADMSG,
+        Overflow => EOVERFLOW,
+        IllegalByteSequence => EILSEQ,
+        NotASocket => ENOTSOCK,
+        DestinationAddressRequired => EDESTADDRREQ,
+        MessageTooLong => EMSGSIZE,
+        WrongProtocolType => EPROTOTYPE,
+        ProtocolOptionUnavailable => ENOPROTOOPT,
+        ProtocolNotSupported => EPROTONOSUPPORT,
+        SocketTypeNotSupported => ESOCKTNOSUPPORT,
+        AddressFamilyNotSupported => EAFNOSUPPORT,
+        AddrNotAvailable => EADDRNOTAVAIL,
+        NetworkDown => ENETDOWN,
+        NetworkUnreachable => ENETUNREACH,
+        ConnectionAborted => ECONNABORTED,
+        NoBufferSpace => ENOBUFS,
+        AlreadyConnected => EISCONN,
+        EndpointShutdown => ESHUTDOWN,
+        ConnectionTimedOut => ETIMEDOUT,
+        HostDown => EHOSTDOWN,
+        HostUnreachable => EHOSTUNREACH,
+        AlreadyInProgress => EALREADY,
+        InProgress => EINPROGRESS,
+        StaleNetworkFileHandle => ESTALE,
+        QuotaExceeded => EDQUOT,
+        NoMedium => ENOMEDIUM,
+        Canceled => ECANCELED,
+        HardwareMemoryError => EHWPOISON,
+        ProbeDeferred => EPROBE_DEFER,
+        NoStreamResources => ENOSR,
+        NotAStream => ENOSTR,
+        LinkSevered => ENOLINK,
+        ArgumentOutOfDomain => EDOM,
+        OwnerDead => EOWNERDEAD,
+        NotRecoverable => ENOTRECOVERABLE,
+        RfKill => ERFKILL,
+        BadSharedLibrary => ELIBBAD,
+        CommunicationError => ECOMM,
+        RemoteIo => EREMOTEIO,
+    }
+    lossy {
+        BadState => EFAULT,
+        InvalidData => EINVAL,
+        UnexpectedEof => EIO,
+        WriteZero => EIO,
+        Again => EAGAIN,
+        Busy => EBUSY,
+    }
+}
+
+impl From<core::num::ParseIntError> for AxError {
+    fn from(_: core::num::ParseIntError) -> Self {
+        AxError::InvalidData
+    }
+}
+
+impl From<core::num::ParseFloatError> for AxError {
+    fn from(_: core::num::ParseFloatError) -> Self {
+        AxError::InvalidData
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<core::alloc::AllocError> for AxError {
+    fn from(_: core::alloc::AllocError) -> Self {
+        AxError::NoMemory
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<alloc::collections::TryReserveError> for AxError {
+    fn from(_: alloc::collections::TryReserveError) -> Self {
+        AxError::NoMemory
+    }
+}
+
+/// Compares using the [`AxError`] to [`LinuxError`] conversion, so several
+/// [`AxError`]s may equal the same [`LinuxError`].
+impl PartialEq<LinuxError> for AxError {
+    fn eq(&self, other: &LinuxError) -> bool {
+        LinuxError::from(*self) == *other
+    }
+}
+
+/// Compares using the [`AxError`] to [`LinuxError`] conversion, so several
+/// [`AxError`]s may equal the same [`LinuxError`].
+impl PartialEq<AxError> for LinuxError {
+    fn eq(&self, other: &AxError) -> bool {
+        *self == LinuxError::from(*other)
+    }
+}
+
+/// Aliases of error codes.
+///
+/// They are equal to the code they alias, so matching on either name matches
+/// both. In particular, `ENOTSUP` and `EOPNOTSUPP` are the same code on Linux
+/// but distinct on other systems, so they are both mapped to
+/// [`AxError::NotSupported`], and an [`ErrnoTranslator`] tells them apart for
+/// other ABIs if needed.
+///
+/// [`ErrnoTranslator`]: personality::ErrnoTranslator
+impl LinuxError {
+    /// Alias of [`EAGAIN`](Self::EAGAIN).
+    pub const EWOULDBLOCK: Self = Self::EAGAIN;
+    /// Alias of [`EDEADLK`](Self::EDEADLK).
+    pub const EDEADLOCK: Self = Self::EDEADLK;
+    /// Alias of [`EOPNOTSUPP`](Self::EOPNOTSUPP), as defined by glibc and musl.
+    pub const ENOTSUP: Self = Self::EOPNOTSUPP;
+}
+
+impl LinuxError {
+    /// Returns `true` if the error is kernel-internal (e.g.
+    /// [`ERESTARTSYS`](LinuxError::ERESTARTSYS)) and must never be returned
+    /// to user space.
+    pub const fn is_kernel_internal(&self) -> bool {
+        self.code() >= 512
     }
 }
 
 impl fmt::Display for LinuxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        f.write_str(self.message())
     }
 }
 
+/// Writes `prefix: message` and a newline to `w`, like `perror(3)`, where
+/// `message` is the [`strerror`](LinuxError::strerror) message of `err`.
+///
+/// If `prefix` is empty, only the message is written.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{perror, AxError};
+/// #
+/// let mut out = String::new();
+/// perror("open", AxError::NotFound, &mut out).unwrap();
+/// perror("", AxError::PermissionDenied, &mut out).unwrap();
+/// assert_eq!(out, "open: No such file or directory\nPermission denied\n");
+/// ```
+pub fn perror(prefix: &str, err: impl Into<AxError>, w: &mut impl fmt::Write) -> fmt::Result {
+    let msg = LinuxError::from(err.into()).strerror();
+    if prefix.is_empty() {
+        writeln!(w, "{msg}")
+    } else {
+        writeln!(w, "{prefix}: {msg}")
+    }
+}
+
+/// Writes [`LINUX_ERRNO_TABLE`] to `w`, one code per line, for an `errno`
+/// command of a debug shell.
+///
+/// Each line has the number, the name and the message of the code. With the
+/// `stats` feature, the number of times the code was
+/// [returned](stats::returned) by a syscall is inserted before the message.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::dump_table;
+/// #
+/// let mut out = String::new();
+/// dump_table(&mut out).unwrap();
+/// let first = out.lines().next().unwrap();
+/// assert!(first.starts_with("   1 EPERM"));
+/// assert!(first.ends_with("Operation not permitted"));
+/// ```
+pub fn dump_table(w: &mut impl fmt::Write) -> fmt::Result {
+    for &(num, name, message) in LINUX_ERRNO_TABLE {
+        write!(w, "{num:>4} {name:<16} ")?;
+        #[cfg(feature = "stats")]
+        if let Some(err) = LinuxError::new(num) {
+            write!(w, "{:>8} ", stats::returned(err))?;
+        }
+        writeln!(w, "{message}")?;
+    }
+    Ok(())
+}
+
 #[doc(hidden)]
 pub mod __priv {
+    pub use core::sync::atomic::{AtomicBool, Ordering};
+    #[cfg(feature = "log")]
     pub use log::warn;
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::AxError;
+    use crate::{AxError, AxResult, LinuxError, LINUX_ERRNO_TABLE};
+
+    #[test]
+    #[should_panic(expected = "inode 3: AxError::NotFound (ENOENT): Entity not found")]
+    fn test_expect_ok() {
+        let ino = 3;
+        assert_eq!(expect_ok!(AxResult::Ok(ino)), 3);
+        expect_ok!(AxResult::<()>::Err(AxError::NotFound), "inode {ino}");
+    }
 
     #[test]
     fn test_try_from() {
         let max_code = core::mem::variant_count::<AxError>() as i32;
-        assert_eq!(max_code, 24);
-        assert_eq!(max_code, AxError::Timeout.code());
+        assert_eq!(max_code, 95);
+        assert_eq!(max_code, AxError::RemoteIo.code());
 
         assert_eq!(AxError::AddrInUse.code(), 1);
         assert_eq!(Ok(AxError::AddrInUse), AxError::try_from(1));
         assert_eq!(Ok(AxError::AlreadyExists), AxError::try_from(2));
-        assert_eq!(Ok(AxError::Timeout), AxError::try_from(max_code));
+        assert_eq!(Ok(AxError::RemoteIo), AxError::try_from(max_code));
         assert_eq!(Err(max_code + 1), AxError::try_from(max_code + 1));
         assert_eq!(Err(0), AxError::try_from(0));
         assert_eq!(Err(-1), AxError::try_from(-1));
         assert_eq!(Err(i32::MAX), AxError::try_from(i32::MAX));
     }
+
+    #[test]
+    fn test_strerror() {
+        assert_eq!(LinuxError::EBADF.strerror(), "Bad file descriptor");
+        assert_eq!(
+            LinuxError::EAGAIN.strerror(),
+            "Resource temporarily unavailable"
+        );
+        assert_eq!(
+            LinuxError::ENOTTY.strerror(),
+            "Inappropriate ioctl for device"
+        );
+        if cfg!(feature = "glibc-strerror") {
+            assert_eq!(LinuxError::EBADF.as_str(), "Bad file descriptor");
+        } else {
+            assert_eq!(LinuxError::EBADF.as_str(), "Bad file number");
+        }
+    }
+
+    #[test]
+    fn test_try_from_linux_error() {
+        for code in 1..=core::mem::variant_count::<AxError>() as i32 {
+            let ax = AxError::try_from(code).unwrap();
+            let linux = LinuxError::from(ax);
+            assert_eq!(LinuxError::from(AxError::try_from(linux).unwrap()), linux);
+        }
+        assert_eq!(
+            AxError::try_from(LinuxError::EINVAL),
+            Ok(AxError::InvalidInput)
+        );
+        assert_eq!(
+            AxError::try_from(LinuxError::ECHRNG),
+            Err(LinuxError::ECHRNG)
+        );
+        assert_eq!(
+            AxError::try_from(LinuxError::ENOTSUP),
+            Ok(AxError::NotSupported)
+        );
+        assert_eq!(LinuxError::ENOTSUP, LinuxError::EOPNOTSUPP);
+        assert_eq!(LinuxError::EWOULDBLOCK, AxError::WouldBlock);
+    }
+
+    #[test]
+    fn test_errno_table() {
+        assert_eq!(
+            LINUX_ERRNO_TABLE[0],
+            (1, "EPERM", LinuxError::EPERM.as_str())
+        );
+        assert!(LINUX_ERRNO_TABLE.is_sorted_by_key(|(num, ..)| *num));
+        for &(num, name, message) in LINUX_ERRNO_TABLE {
+            assert!(name.starts_with('E'));
+            assert_eq!(LinuxError::try_from(num).unwrap().as_str(), message);
+        }
+        let max = LINUX_ERRNO_TABLE.last().unwrap().0;
+        assert_eq!(max, LinuxError::MAX_CODE);
+    }
+
+    #[test]
+    fn test_cross_type_eq() {
+        assert_eq!(AxError::NotFound, LinuxError::ENOENT);
+        assert_eq!(LinuxError::EAGAIN, AxError::WouldBlock);
+        assert_eq!(LinuxError::EAGAIN, AxError::Again);
+        assert_ne!(AxError::NotFound, LinuxError::EEXIST);
+    }
+
+    #[test]
+    fn test_from_parse_error() {
+        fn parse(s: &str) -> Result<(u32, f64), AxError> {
+            let (a, b) = s.split_once(',').ok_or(AxError::InvalidInput)?;
+            Ok((a.parse()?, b.parse()?))
+        }
+        assert_eq!(parse("1,2.5"), Ok((1, 2.5)));
+        assert_eq!(parse("x,2.5"), Err(AxError::InvalidData));
+        assert_eq!(parse("1,y"), Err(AxError::InvalidData));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_alloc_error() {
+        use alloc::vec::Vec;
+
+        fn reserve(len: usize) -> AxResult<Vec<u8>> {
+            let mut v = Vec::new();
+            v.try_reserve(len)?;
+            Ok(v)
+        }
+        assert!(reserve(16).is_ok());
+        assert_eq!(reserve(usize::MAX), Err(AxError::NoMemory));
+        assert_eq!(AxError::from(core::alloc::AllocError), AxError::NoMemory);
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(AxError::Interrupted.is_interrupted());
+        assert!(!AxError::Again.is_would_block());
+        assert!(LinuxError::EINTR.is_interrupted());
+        assert!(LinuxError::EAGAIN.is_would_block());
+        assert!(LinuxError::EAGAIN.is_again());
+        assert!(!LinuxError::ENOENT.is_would_block());
+
+        let res: AxResult = Err(AxError::NotFound);
+        assert!(res.is_err_and(|e| e.is_not_found()));
+    }
 }
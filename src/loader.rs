@@ -0,0 +1,109 @@
+//! Executable loading failures.
+//!
+//! [`LoaderError`] keeps the reason an ELF image was rejected, which is
+//! reduced to the error code Linux's `execve` reports at the syscall boundary.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+/// The reason an executable could not be loaded.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{loader::LoaderError, AxError, LinuxError};
+/// #
+/// assert_eq!(LinuxError::from(LoaderError::WrongMachine), LinuxError::ENOEXEC);
+/// assert_eq!(LinuxError::from(LoaderError::BadInterpreter), LinuxError::ELIBBAD);
+/// assert_eq!(AxError::from(LoaderError::BadMagic), AxError::InvalidExecutable);
+/// assert_eq!(AxError::from(LoaderError::BadInterpreter), AxError::BadSharedLibrary);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoaderError {
+    /// The file does not start with the ELF magic.
+    BadMagic,
+    /// The ELF class (32 or 64-bit) does not match the kernel.
+    WrongClass,
+    /// The ELF image is built for another machine.
+    WrongMachine,
+    /// The program interpreter (`PT_INTERP`) is not a valid ELF image.
+    BadInterpreter,
+    /// The dynamic section or program headers are malformed.
+    BadDynamicSection,
+}
+
+impl LoaderError {
+    /// Returns the error description.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::BadMagic => "Bad ELF magic",
+            Self::WrongClass => "Wrong ELF class",
+            Self::WrongMachine => "Wrong ELF machine",
+            Self::BadInterpreter => "Bad program interpreter",
+            Self::BadDynamicSection => "Bad dynamic section",
+        }
+    }
+}
+
+impl From<LoaderError> for LinuxError {
+    fn from(e: LoaderError) -> Self {
+        match e {
+            LoaderError::BadMagic | LoaderError::WrongClass | LoaderError::WrongMachine => {
+                LinuxError::ENOEXEC
+            }
+            LoaderError::BadInterpreter => LinuxError::ELIBBAD,
+            LoaderError::BadDynamicSection => LinuxError::EINVAL,
+        }
+    }
+}
+
+impl From<LoaderError> for AxError {
+    fn from(e: LoaderError) -> Self {
+        match e {
+            LoaderError::BadInterpreter => AxError::BadSharedLibrary,
+            LoaderError::BadDynamicSection => AxError::InvalidData,
+            _ => AxError::InvalidExecutable,
+        }
+    }
+}
+
+impl fmt::Display for LoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loader_error() {
+        assert_eq!(
+            LinuxError::from(LoaderError::WrongClass),
+            LinuxError::ENOEXEC
+        );
+        assert_eq!(
+            LinuxError::from(LoaderError::BadDynamicSection),
+            LinuxError::EINVAL
+        );
+        assert_eq!(
+            AxError::from(LoaderError::BadDynamicSection),
+            AxError::InvalidData
+        );
+        assert_eq!(
+            LinuxError::from(AxError::InvalidExecutable),
+            LinuxError::ENOEXEC
+        );
+        for e in [
+            LoaderError::BadMagic,
+            LoaderError::WrongClass,
+            LoaderError::WrongMachine,
+            LoaderError::BadInterpreter,
+            LoaderError::BadDynamicSection,
+        ] {
+            assert_eq!(LinuxError::from(AxError::from(e)), LinuxError::from(e));
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Errors carrying the source location they were raised at, or the locations
+//! they propagated through.
+
+use core::fmt;
+use core::panic::Location;
+
+use crate::{AxError, AxResult, LinuxError};
+
+/// An [`AxError`] with the source location it was raised at.
+///
+/// It stays `Copy` and needs no allocation, so it can tell where an error came
+/// from even on targets without an allocator. It is constructed by the
+/// `#[track_caller]` functions [`AxErrorAt::new`], [`AxError::at`] and
+/// [`AxResultExt::at`](crate::AxResultExt::at), and by the `From<AxError>`
+/// conversion, so `?` records the location of the `?`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, AxErrorAt, AxResult, AxResultAt};
+/// #
+/// fn lookup() -> AxResult<u32> {
+///     Err(AxError::NotFound)
+/// }
+///
+/// fn open() -> AxResultAt<u32> {
+///     Ok(lookup()? + 1)
+/// }
+///
+/// let err = open().unwrap_err();
+/// assert_eq!(err.kind, AxError::NotFound);
+/// assert_eq!(err.location.file(), file!());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AxErrorAt {
+    /// The error.
+    pub kind: AxError,
+    /// The source location the error was raised at.
+    pub location: &'static Location<'static>,
+}
+
+/// A [`Result`] whose error carries its source location.
+pub type AxResultAt<T = ()> = Result<T, AxErrorAt>;
+
+impl AxErrorAt {
+    /// Constructs an error located at the caller.
+    #[track_caller]
+    pub fn new(kind: AxError) -> Self {
+        Self {
+            kind,
+            location: Location::caller(),
+        }
+    }
+}
+
+impl AxError {
+    /// Attaches the location of the caller to the error.
+    #[track_caller]
+    pub fn at(self) -> AxErrorAt {
+        AxErrorAt::new(self)
+    }
+}
+
+impl From<AxError> for AxErrorAt {
+    #[track_caller]
+    fn from(kind: AxError) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<AxErrorAt> for AxError {
+    fn from(e: AxErrorAt) -> Self {
+        e.kind
+    }
+}
+
+impl From<AxErrorAt> for LinuxError {
+    fn from(e: AxErrorAt) -> Self {
+        e.kind.into()
+    }
+}
+
+impl fmt::Display for AxErrorAt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.kind, self.location)
+    }
+}
+
+/// The locations an error propagated through, recording up to `N` frames
+/// without allocation.
+///
+/// The first frame is where the error was raised. Further frames are pushed
+/// with [`push`](Self::push) or [`TraceExt`] as the error is returned up the
+/// call stack, possibly converting it to another [`AxError`]. Frames beyond
+/// `N` are counted but not recorded.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, AxResult, ErrorTrace, TraceExt};
+/// #
+/// fn read_block() -> AxResult<u8> {
+///     Err(AxError::Io)
+/// }
+///
+/// fn read_inode() -> Result<u8, ErrorTrace<4>> {
+///     read_block().trace()
+/// }
+///
+/// fn open() -> Result<u8, ErrorTrace<4>> {
+///     read_inode().trace_as(AxError::NotFound)
+/// }
+///
+/// let trace = open().unwrap_err();
+/// assert_eq!(trace.kind(), AxError::NotFound);
+/// assert_eq!(trace.root().kind, AxError::Io);
+/// assert_eq!(trace.frames().len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorTrace<const N: usize> {
+    frames: [AxErrorAt; N],
+    len: usize,
+    dropped: usize,
+    kind: AxError,
+}
+
+impl<const N: usize> ErrorTrace<N> {
+    /// Starts a trace of `err` raised at the caller.
+    #[track_caller]
+    pub fn new(err: AxError) -> Self {
+        let frame = AxErrorAt::new(err);
+        Self {
+            frames: [frame; N],
+            len: N.min(1),
+            dropped: 1 - N.min(1),
+            kind: err,
+        }
+    }
+
+    /// Records that the error, now `err`, propagated through the caller.
+    #[track_caller]
+    pub fn push(&mut self, err: AxError) {
+        self.kind = err;
+        match self.frames.get_mut(self.len) {
+            Some(frame) => {
+                *frame = AxErrorAt::new(err);
+                self.len += 1;
+            }
+            None => self.dropped += 1,
+        }
+    }
+
+    /// Returns the current error, i.e. that of the last pushed frame.
+    pub const fn kind(&self) -> AxError {
+        self.kind
+    }
+
+    /// Returns the frame where the error was raised.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn root(&self) -> AxErrorAt {
+        self.frames[0]
+    }
+
+    /// Returns the recorded frames, from where the error was raised outwards.
+    pub fn frames(&self) -> &[AxErrorAt] {
+        &self.frames[..self.len]
+    }
+
+    /// Returns the number of frames that were not recorded for lack of
+    /// capacity.
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+impl<const N: usize> From<AxError> for ErrorTrace<N> {
+    #[track_caller]
+    fn from(err: AxError) -> Self {
+        Self::new(err)
+    }
+}
+
+impl<const N: usize> From<ErrorTrace<N>> for AxError {
+    fn from(trace: ErrorTrace<N>) -> Self {
+        trace.kind
+    }
+}
+
+impl<const N: usize> From<ErrorTrace<N>> for LinuxError {
+    fn from(trace: ErrorTrace<N>) -> Self {
+        trace.kind.into()
+    }
+}
+
+impl<const N: usize> fmt::Display for ErrorTrace<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for frame in self.frames().iter().rev() {
+            write!(f, "\n    {frame}")?;
+        }
+        if self.dropped > 0 {
+            write!(f, "\n    ... {} more", self.dropped)?;
+        }
+        Ok(())
+    }
+}
+
+/// Extension methods pushing frames to an [`ErrorTrace`].
+pub trait TraceExt<T, const N: usize> {
+    /// Records that the error propagated through the caller, starting a
+    /// trace if there is none.
+    #[track_caller]
+    fn trace(self) -> Result<T, ErrorTrace<N>>;
+
+    /// Like [`trace`](Self::trace), but also replaces the error with `err`.
+    #[track_caller]
+    fn trace_as(self, err: AxError) -> Result<T, ErrorTrace<N>>;
+}
+
+impl<T, const N: usize> TraceExt<T, N> for AxResult<T> {
+    #[track_caller]
+    fn trace(self) -> Result<T, ErrorTrace<N>> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(ErrorTrace::new(e)),
+        }
+    }
+
+    #[track_caller]
+    fn trace_as(self, err: AxError) -> Result<T, ErrorTrace<N>> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                let mut trace = ErrorTrace::new(e);
+                trace.push(err);
+                Err(trace)
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> TraceExt<T, N> for Result<T, ErrorTrace<N>> {
+    #[track_caller]
+    fn trace(self) -> Result<T, ErrorTrace<N>> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(trace) => Err(trace).trace_as(trace.kind),
+        }
+    }
+
+    #[track_caller]
+    fn trace_as(self, err: AxError) -> Result<T, ErrorTrace<N>> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(mut trace) => {
+                trace.push(err);
+                Err(trace)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxResult, AxResultExt};
+
+    #[test]
+    fn test_located() {
+        let line = line!() + 1;
+        let err = AxError::InvalidInput.at();
+        assert_eq!((err.location.file(), err.location.line()), (file!(), line));
+        assert_eq!(AxError::from(err), AxError::InvalidInput);
+        assert_eq!(LinuxError::from(err), LinuxError::EINVAL);
+
+        let res: AxResult<()> = Err(AxError::Io);
+        let line = line!() + 1;
+        let err = res.at().unwrap_err();
+        assert_eq!(err.location.line(), line);
+        assert_eq!(AxResult::Ok(1).at(), Ok(1));
+
+        let line = line!() + 2;
+        let propagate = || -> AxResultAt {
+            Err(AxError::Busy)?;
+            Ok(())
+        };
+        assert_eq!(propagate().unwrap_err().location.line(), line);
+    }
+
+    #[test]
+    fn test_error_trace() {
+        let mut trace = ErrorTrace::<2>::new(AxError::Io);
+        let line = line!() + 1;
+        trace.push(AxError::Busy);
+        trace.push(AxError::NotFound);
+        assert_eq!(trace.kind(), AxError::NotFound);
+        assert_eq!(trace.frames().len(), 2);
+        assert_eq!(trace.frames()[1].kind, AxError::Busy);
+        assert_eq!(trace.frames()[1].location.line(), line);
+        assert_eq!(trace.dropped(), 1);
+        assert_eq!(LinuxError::from(trace), LinuxError::ENOENT);
+
+        let empty = ErrorTrace::<0>::new(AxError::Io);
+        assert_eq!(empty.frames(), []);
+        assert_eq!(empty.dropped(), 1);
+
+        let res: AxResult<u8> = Ok(1);
+        assert_eq!(TraceExt::<_, 2>::trace(res), Ok(1));
+        let res: AxResult = Err(AxError::Io);
+        let trace: ErrorTrace<4> = res.trace().trace().unwrap_err();
+        assert_eq!(trace.root().location.file(), file!());
+        assert_eq!(trace.frames().len(), 2);
+        assert!(trace.frames().iter().all(|f| f.kind == AxError::Io));
+    }
+}
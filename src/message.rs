@@ -0,0 +1,57 @@
+//! Runtime overrides of the error messages.
+//!
+//! The OS can register a [`MessageProvider`] with [`set_message_provider`] to
+//! supply its own messages, e.g. translated ones or messages for codes of a
+//! custom [`ErrnoTable`](crate::personality::ErrnoTable), without rebuilding
+//! this crate. The `Display` implementations of [`AxError`] and
+//! [`LinuxError`] use [`AxError::message`] and [`LinuxError::message`], which
+//! consult the provider before the built-in tables.
+
+use crate::global::GlobalRef;
+use crate::{AxError, AxResult, LinuxError};
+
+/// Supplies error messages overriding the built-in ones.
+pub trait MessageProvider: Sync {
+    /// Returns the message of `err`, or `None` to use the built-in one.
+    fn ax_message(&self, err: AxError) -> Option<&'static str> {
+        let _ = err;
+        None
+    }
+
+    /// Returns the message of `err`, or `None` to use the built-in one.
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        let _ = err;
+        None
+    }
+}
+
+static PROVIDER: GlobalRef<dyn MessageProvider> = GlobalRef::new();
+
+/// Registers the message provider.
+///
+/// Returns [`AxError::AlreadyExists`] if a provider is already registered.
+pub fn set_message_provider(provider: &'static dyn MessageProvider) -> AxResult {
+    PROVIDER.set(provider)
+}
+
+impl AxError {
+    /// Returns the message of the registered [`MessageProvider`], or
+    /// [`as_str`](Self::as_str) if there is none.
+    pub fn message(self) -> &'static str {
+        PROVIDER
+            .get()
+            .and_then(|p| p.ax_message(self))
+            .unwrap_or_else(|| self.as_str())
+    }
+}
+
+impl LinuxError {
+    /// Returns the message of the registered [`MessageProvider`], or
+    /// [`as_str`](Self::as_str) if there is none.
+    pub fn message(self) -> &'static str {
+        PROVIDER
+            .get()
+            .and_then(|p| p.linux_message(self))
+            .unwrap_or_else(|| self.as_str())
+    }
+}
@@ -0,0 +1,98 @@
+//! Page fault outcomes.
+//!
+//! The trap handler classifies an unresolved page fault as a [`FaultKind`],
+//! then either reports an error code when the fault happened while accessing
+//! user memory on behalf of a syscall, or delivers a signal when user code
+//! faulted itself.
+
+use crate::LinuxError;
+
+/// The reason a page fault could not be resolved.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::mm::{FaultKind, FaultSignal};
+/// # use axerrno::LinuxError;
+/// #
+/// let kind = FaultKind::ProtectionViolation;
+/// assert_eq!(kind.errno(), LinuxError::EFAULT);
+/// assert_eq!(kind.signal(), FaultSignal::SEGV_ACCERR);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FaultKind {
+    /// No mapping covers the faulting address.
+    NotMapped,
+    /// The mapping does not permit the access.
+    ProtectionViolation,
+    /// Memory ran out while allocating the page.
+    OomDuringFault,
+    /// A copy-on-write page could not be copied.
+    CowFailure,
+    /// The page has an uncorrectable hardware memory error.
+    HwPoison,
+}
+
+/// A signal delivered for a page fault, with its `si_code`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FaultSignal {
+    /// The signal number.
+    pub signo: i32,
+    /// The `si_code` of the signal.
+    pub code: i32,
+}
+
+impl FaultSignal {
+    /// `SIGSEGV` with `SEGV_MAPERR`: address not mapped.
+    pub const SEGV_MAPERR: Self = Self { signo: 11, code: 1 };
+    /// `SIGSEGV` with `SEGV_ACCERR`: invalid permissions for the mapping.
+    pub const SEGV_ACCERR: Self = Self { signo: 11, code: 2 };
+    /// `SIGBUS` with `BUS_ADRERR`: the page cannot be provided.
+    pub const BUS_ADRERR: Self = Self { signo: 7, code: 2 };
+    /// `SIGBUS` with `BUS_MCEERR_AR`: hardware memory error consumed.
+    pub const BUS_MCEERR_AR: Self = Self { signo: 7, code: 4 };
+    /// `SIGKILL`, as sent when the task is chosen by the OOM killer.
+    pub const KILL: Self = Self { signo: 9, code: 0 };
+}
+
+impl FaultKind {
+    /// Returns the error code for a fault on a user access made by the
+    /// kernel, e.g. in `copy_from_user`.
+    pub const fn errno(self) -> LinuxError {
+        match self {
+            Self::NotMapped | Self::ProtectionViolation | Self::CowFailure => LinuxError::EFAULT,
+            Self::OomDuringFault => LinuxError::ENOMEM,
+            Self::HwPoison => LinuxError::EHWPOISON,
+        }
+    }
+
+    /// Returns the signal delivered for a fault in user code.
+    pub const fn signal(self) -> FaultSignal {
+        match self {
+            Self::NotMapped => FaultSignal::SEGV_MAPERR,
+            Self::ProtectionViolation => FaultSignal::SEGV_ACCERR,
+            Self::OomDuringFault => FaultSignal::KILL,
+            Self::CowFailure => FaultSignal::BUS_ADRERR,
+            Self::HwPoison => FaultSignal::BUS_MCEERR_AR,
+        }
+    }
+}
+
+impl From<FaultKind> for LinuxError {
+    fn from(kind: FaultKind) -> Self {
+        kind.errno()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fault_kind() {
+        assert_eq!(FaultKind::NotMapped.signal(), FaultSignal::SEGV_MAPERR);
+        assert_eq!(FaultKind::OomDuringFault.errno(), LinuxError::ENOMEM);
+        assert_eq!(FaultKind::HwPoison.signal().signo, 7);
+        assert_eq!(LinuxError::from(FaultKind::HwPoison), LinuxError::EHWPOISON);
+    }
+}
@@ -0,0 +1,243 @@
+//! Error helpers for the network stack.
+
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use crate::{LinuxError, LinuxResult};
+
+/// Encodes a pending socket error as the `int` returned by
+/// `getsockopt(SO_ERROR)`, where `0` means no error.
+pub const fn encode_so_error(err: Option<LinuxError>) -> i32 {
+    match err {
+        Some(err) => err.code(),
+        None => 0,
+    }
+}
+
+/// Decodes the `int` returned by `getsockopt(SO_ERROR)`.
+///
+/// Returns the raw value if it is neither `0` nor a valid error code.
+pub fn decode_so_error(raw: i32) -> Result<Option<LinuxError>, i32> {
+    match raw {
+        0 => Ok(None),
+        _ => LinuxError::try_from(raw).map(Some),
+    }
+}
+
+/// The pending error of a socket (`sk_err` in Linux).
+///
+/// It is set when an asynchronous error occurs and cleared when read by
+/// `getsockopt(SO_ERROR)` or reported by the next socket operation.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{net::SoError, LinuxError};
+/// #
+/// let pending = SoError::new();
+/// pending.set(LinuxError::ECONNREFUSED);
+/// assert_eq!(pending.take_raw(), LinuxError::ECONNREFUSED.code());
+/// assert_eq!(pending.take_raw(), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct SoError(AtomicI32);
+
+impl SoError {
+    /// Constructs a socket error slot with no pending error.
+    pub const fn new() -> Self {
+        Self(AtomicI32::new(0))
+    }
+
+    /// Records `err` as the pending error, replacing any previous one.
+    pub fn set(&self, err: LinuxError) {
+        self.0.store(err.code(), Ordering::Release);
+    }
+
+    /// Returns the pending error without clearing it.
+    pub fn peek(&self) -> Option<LinuxError> {
+        decode_so_error(self.0.load(Ordering::Acquire)).unwrap_or(None)
+    }
+
+    /// Returns `true` if there is a pending error.
+    pub fn is_pending(&self) -> bool {
+        self.0.load(Ordering::Acquire) != 0
+    }
+
+    /// Takes and clears the pending error.
+    pub fn take(&self) -> Option<LinuxError> {
+        decode_so_error(self.0.swap(0, Ordering::AcqRel)).unwrap_or(None)
+    }
+
+    /// Takes and clears the pending error, returning it as `Err`.
+    pub fn take_result(&self) -> LinuxResult {
+        match self.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Takes and clears the pending error, encoded as the value of
+    /// `getsockopt(SO_ERROR)`.
+    pub fn take_raw(&self) -> i32 {
+        self.0.swap(0, Ordering::AcqRel)
+    }
+}
+
+/// No origin.
+pub const SO_EE_ORIGIN_NONE: u8 = 0;
+/// The error was generated locally, e.g. `EMSGSIZE` for an oversized datagram.
+pub const SO_EE_ORIGIN_LOCAL: u8 = 1;
+/// The error was reported by an ICMP message.
+pub const SO_EE_ORIGIN_ICMP: u8 = 2;
+/// The error was reported by an ICMPv6 message.
+pub const SO_EE_ORIGIN_ICMP6: u8 = 3;
+/// A transmit timestamp or status notification.
+pub const SO_EE_ORIGIN_TXSTATUS: u8 = 4;
+/// A zero-copy transmit completion notification.
+pub const SO_EE_ORIGIN_ZEROCOPY: u8 = 5;
+/// A packet dropped because its transmit time was missed.
+pub const SO_EE_ORIGIN_TXTIME: u8 = 6;
+
+/// The error queued on a socket and read with `recvmsg(MSG_ERRQUEUE)`, as the
+/// `IP_RECVERR`/`IPV6_RECVERR` control message (`struct sock_extended_err`).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{net::{SockExtendedErr, SO_EE_ORIGIN_ICMP}, LinuxError};
+/// #
+/// // ICMP port unreachable.
+/// let ee = SockExtendedErr::icmp(LinuxError::ECONNREFUSED, 3, 3);
+/// assert_eq!(ee.ee_origin, SO_EE_ORIGIN_ICMP);
+/// assert_eq!(ee.errno(), Some(LinuxError::ECONNREFUSED));
+/// assert_eq!(ee.to_bytes()[..4], 111u32.to_ne_bytes());
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SockExtendedErr {
+    /// The positive error code.
+    pub ee_errno: u32,
+    /// Where the error came from, one of the `SO_EE_ORIGIN_*` constants.
+    pub ee_origin: u8,
+    /// The ICMP type, for ICMP origins.
+    pub ee_type: u8,
+    /// The ICMP code, for ICMP origins.
+    pub ee_code: u8,
+    /// Padding.
+    pub ee_pad: u8,
+    /// Extra information, e.g. the path MTU for `EMSGSIZE`.
+    pub ee_info: u32,
+    /// Extra data, e.g. the range of completed zero-copy sends.
+    pub ee_data: u32,
+}
+
+impl SockExtendedErr {
+    /// The size of `struct sock_extended_err`.
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Constructs an error from `origin` with no extra information.
+    pub const fn new(err: LinuxError, origin: u8) -> Self {
+        Self {
+            ee_errno: err.code() as u32,
+            ee_origin: origin,
+            ee_type: 0,
+            ee_code: 0,
+            ee_pad: 0,
+            ee_info: 0,
+            ee_data: 0,
+        }
+    }
+
+    /// Constructs a locally generated error.
+    pub const fn local(err: LinuxError) -> Self {
+        Self::new(err, SO_EE_ORIGIN_LOCAL)
+    }
+
+    /// Constructs an error reported by an ICMP message of type `ty` and code
+    /// `code`.
+    pub const fn icmp(err: LinuxError, ty: u8, code: u8) -> Self {
+        Self::new(err, SO_EE_ORIGIN_ICMP).with_icmp(ty, code)
+    }
+
+    /// Constructs an error reported by an ICMPv6 message of type `ty` and
+    /// code `code`.
+    pub const fn icmp6(err: LinuxError, ty: u8, code: u8) -> Self {
+        Self::new(err, SO_EE_ORIGIN_ICMP6).with_icmp(ty, code)
+    }
+
+    /// Sets the ICMP type and code.
+    pub const fn with_icmp(mut self, ty: u8, code: u8) -> Self {
+        self.ee_type = ty;
+        self.ee_code = code;
+        self
+    }
+
+    /// Sets [`ee_info`](Self::ee_info).
+    pub const fn with_info(mut self, info: u32) -> Self {
+        self.ee_info = info;
+        self
+    }
+
+    /// Sets [`ee_data`](Self::ee_data).
+    pub const fn with_data(mut self, data: u32) -> Self {
+        self.ee_data = data;
+        self
+    }
+
+    /// Returns the error code, or `None` if it is not a valid code.
+    pub fn errno(&self) -> Option<LinuxError> {
+        LinuxError::try_from(i32::try_from(self.ee_errno).ok()?).ok()
+    }
+
+    /// Returns the control message payload in native byte order.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut buf = [0; Self::SIZE];
+        buf[..4].copy_from_slice(&self.ee_errno.to_ne_bytes());
+        buf[4..8].copy_from_slice(&[self.ee_origin, self.ee_type, self.ee_code, self.ee_pad]);
+        buf[8..12].copy_from_slice(&self.ee_info.to_ne_bytes());
+        buf[12..].copy_from_slice(&self.ee_data.to_ne_bytes());
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_so_error() {
+        let pending = SoError::new();
+        assert!(!pending.is_pending());
+        assert_eq!(pending.take_result(), Ok(()));
+
+        pending.set(LinuxError::ETIMEDOUT);
+        assert_eq!(pending.peek(), Some(LinuxError::ETIMEDOUT));
+        assert_eq!(pending.take_result(), Err(LinuxError::ETIMEDOUT));
+        assert_eq!(pending.take(), None);
+
+        assert_eq!(encode_so_error(None), 0);
+        assert_eq!(decode_so_error(0), Ok(None));
+        assert_eq!(decode_so_error(104), Ok(Some(LinuxError::ECONNRESET)));
+        assert_eq!(decode_so_error(-1), Err(-1));
+    }
+
+    #[test]
+    fn test_sock_extended_err() {
+        assert_eq!(SockExtendedErr::SIZE, 16);
+        let ee = SockExtendedErr::local(LinuxError::EMSGSIZE).with_info(1400);
+        assert_eq!(ee.ee_origin, SO_EE_ORIGIN_LOCAL);
+        assert_eq!(ee.errno(), Some(LinuxError::EMSGSIZE));
+
+        let bytes = SockExtendedErr::icmp6(LinuxError::EHOSTUNREACH, 1, 3)
+            .with_data(7)
+            .to_bytes();
+        assert_eq!(bytes[..4], 113u32.to_ne_bytes());
+        assert_eq!(bytes[4..8], [SO_EE_ORIGIN_ICMP6, 1, 3, 0]);
+        assert_eq!(bytes[8..12], [0; 4]);
+        assert_eq!(bytes[12..], 7u32.to_ne_bytes());
+
+        let mut ee = SockExtendedErr::default();
+        assert_eq!(ee.errno(), None);
+        ee.ee_errno = u32::MAX;
+        assert_eq!(ee.errno(), None);
+    }
+}
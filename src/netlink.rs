@@ -0,0 +1,207 @@
+//! Error codes in netlink `NLMSG_ERROR` messages.
+//!
+//! The `error` field of `struct nlmsgerr` is `0` for an acknowledgement and a
+//! negated error code otherwise. With extended acknowledgements, the message
+//! carries `NLMSGERR_ATTR_*` attributes after the echoed request, announced by
+//! [`NLM_F_ACK_TLVS`] in the header flags.
+
+use crate::{LinuxError, LinuxResult};
+
+/// Header flag of an `NLMSG_ERROR` message whose echoed request is truncated
+/// to its header.
+pub const NLM_F_CAPPED: u16 = 0x100;
+/// Header flag of an `NLMSG_ERROR` message carrying extended-ack attributes.
+pub const NLM_F_ACK_TLVS: u16 = 0x200;
+
+/// Extended-ack attribute: a NUL-terminated error message.
+pub const NLMSGERR_ATTR_MSG: u16 = 1;
+/// Extended-ack attribute: the `u32` offset of the invalid attribute in the
+/// request.
+pub const NLMSGERR_ATTR_OFFS: u16 = 2;
+/// Extended-ack attribute: an arbitrary cookie.
+pub const NLMSGERR_ATTR_COOKIE: u16 = 3;
+
+/// The size of `struct nlattr`.
+const NLA_HDRLEN: usize = 4;
+
+const fn nla_align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Encodes the `error` field of `struct nlmsgerr`: `0` for an
+/// acknowledgement, the negated error code otherwise.
+pub const fn encode_error(res: LinuxResult) -> i32 {
+    match res {
+        Ok(()) => 0,
+        Err(err) => -err.code(),
+    }
+}
+
+/// Decodes the `error` field of `struct nlmsgerr`.
+///
+/// Returns the raw value if it is neither `0` nor a negated error code.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{netlink::decode_error, LinuxError};
+/// #
+/// assert_eq!(decode_error(0), Ok(Ok(())));
+/// assert_eq!(decode_error(-17), Ok(Err(LinuxError::EEXIST)));
+/// assert_eq!(decode_error(17), Err(17));
+/// ```
+pub fn decode_error(raw: i32) -> Result<LinuxResult, i32> {
+    match raw {
+        0 => Ok(Ok(())),
+        _ => raw
+            .checked_neg()
+            .and_then(LinuxError::new)
+            .map(Err)
+            .ok_or(raw),
+    }
+}
+
+/// The extended-ack attributes of an `NLMSG_ERROR` message.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::netlink::{ExtAck, NLM_F_ACK_TLVS};
+/// #
+/// let ack = ExtAck { msg: Some("unknown device"), offset: Some(20) };
+/// assert_eq!(ack.flags(), NLM_F_ACK_TLVS);
+///
+/// let mut buf = [0; 64];
+/// let len = ack.encode(&mut buf).unwrap();
+/// assert_eq!(len, ack.encoded_len());
+/// assert_eq!(ExtAck::parse(&buf[..len]), ack);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ExtAck<'a> {
+    /// The error message ([`NLMSGERR_ATTR_MSG`]), without the NUL
+    /// terminator.
+    pub msg: Option<&'a str>,
+    /// The offset of the invalid attribute in the request
+    /// ([`NLMSGERR_ATTR_OFFS`]).
+    pub offset: Option<u32>,
+}
+
+impl<'a> ExtAck<'a> {
+    /// Returns the header flags announcing the attributes:
+    /// [`NLM_F_ACK_TLVS`] if there is any, `0` otherwise.
+    pub const fn flags(&self) -> u16 {
+        if self.msg.is_some() || self.offset.is_some() {
+            NLM_F_ACK_TLVS
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of bytes [`encode`](Self::encode) writes.
+    pub const fn encoded_len(&self) -> usize {
+        let mut len = 0;
+        if let Some(msg) = self.msg {
+            len += nla_align(NLA_HDRLEN + msg.len() + 1);
+        }
+        if self.offset.is_some() {
+            len += NLA_HDRLEN + 4;
+        }
+        len
+    }
+
+    /// Writes the attributes to `buf` in native byte order and returns the
+    /// number of bytes written, or `None` if `buf` is too small.
+    pub fn encode(&self, buf: &mut [u8]) -> Option<usize> {
+        let buf = buf.get_mut(..self.encoded_len())?;
+        buf.fill(0);
+        let mut pos = 0;
+        if let Some(msg) = self.msg {
+            pos += put_attr(&mut buf[pos..], NLMSGERR_ATTR_MSG, msg.as_bytes(), 1);
+        }
+        if let Some(offset) = self.offset {
+            pos += put_attr(
+                &mut buf[pos..],
+                NLMSGERR_ATTR_OFFS,
+                &offset.to_ne_bytes(),
+                0,
+            );
+        }
+        Some(pos)
+    }
+
+    /// Parses the attributes following the echoed request.
+    ///
+    /// Unknown and malformed attributes are ignored.
+    pub fn parse(mut buf: &'a [u8]) -> Self {
+        let mut ack = Self::default();
+        while buf.len() >= NLA_HDRLEN {
+            let len = u16::from_ne_bytes([buf[0], buf[1]]) as usize;
+            let ty = u16::from_ne_bytes([buf[2], buf[3]]);
+            let Some(payload) = buf.get(NLA_HDRLEN..len.max(NLA_HDRLEN)) else {
+                break;
+            };
+            match ty {
+                NLMSGERR_ATTR_MSG => {
+                    let msg = payload.split(|&b| b == 0).next().unwrap_or_default();
+                    ack.msg = core::str::from_utf8(msg).ok();
+                }
+                NLMSGERR_ATTR_OFFS => {
+                    ack.offset = payload.try_into().ok().map(u32::from_ne_bytes);
+                }
+                _ => {}
+            }
+            buf = buf
+                .get(nla_align(len).max(NLA_HDRLEN)..)
+                .unwrap_or_default();
+        }
+        ack
+    }
+}
+
+/// Writes an attribute with `payload` followed by `nul` zero bytes, and
+/// returns its aligned length. `buf` must be zeroed and large enough.
+fn put_attr(buf: &mut [u8], ty: u16, payload: &[u8], nul: usize) -> usize {
+    let len = NLA_HDRLEN + payload.len() + nul;
+    buf[..2].copy_from_slice(&(len as u16).to_ne_bytes());
+    buf[2..4].copy_from_slice(&ty.to_ne_bytes());
+    buf[NLA_HDRLEN..NLA_HDRLEN + payload.len()].copy_from_slice(payload);
+    nla_align(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nlmsg_error() {
+        assert_eq!(encode_error(Ok(())), 0);
+        assert_eq!(encode_error(Err(LinuxError::ENODEV)), -19);
+        assert_eq!(decode_error(-19), Ok(Err(LinuxError::ENODEV)));
+        assert_eq!(decode_error(i32::MIN), Err(i32::MIN));
+        assert_eq!(decode_error(-4096), Err(-4096));
+    }
+
+    #[test]
+    fn test_ext_ack() {
+        let empty = ExtAck::default();
+        assert_eq!(empty.flags(), 0);
+        assert_eq!(empty.encode(&mut []), Some(0));
+
+        let ack = ExtAck {
+            msg: Some("bad"),
+            offset: None,
+        };
+        let mut buf = [0xff; 8];
+        assert_eq!(ack.encode(&mut buf[..7]), None);
+        assert_eq!(ack.encode(&mut buf), Some(8));
+        assert_eq!(buf[4..], *b"bad\0");
+        assert_eq!(ExtAck::parse(&buf), ack);
+
+        // An unknown attribute, then a truncated one.
+        let mut buf = [0; 12];
+        buf[..2].copy_from_slice(&8u16.to_ne_bytes());
+        buf[2..4].copy_from_slice(&NLMSGERR_ATTR_COOKIE.to_ne_bytes());
+        buf[8..10].copy_from_slice(&100u16.to_ne_bytes());
+        assert_eq!(ExtAck::parse(&buf), ExtAck::default());
+    }
+}
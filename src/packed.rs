@@ -0,0 +1,230 @@
+//! Results packed into a single machine word.
+//!
+//! `Result<usize, LinuxError>` takes two registers. [`PackedResult`] encodes
+//! it into one with the negative-errno convention of
+//! [`SyscallResult`](crate::syscall::SyscallResult), for assembly trampolines
+//! and per-CPU fast paths where the second register matters.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::syscall::MAX_ERRNO;
+use crate::{AxResult, LinuxError, LinuxResult};
+
+/// A value that can be stored in a [`PackedResult`].
+///
+/// The word returned by [`into_word`](Self::into_word) must stay below the
+/// error range, i.e. not exceed `usize::MAX - MAX_ERRNO`, or it reads back as
+/// an error.
+pub trait PackedValue: Copy {
+    /// Converts the value to a word.
+    fn into_word(self) -> usize;
+
+    /// Converts a word returned by [`into_word`](Self::into_word) back.
+    fn from_word(word: usize) -> Self;
+}
+
+macro_rules! packed_value {
+    ($($ty: ty),+) => {
+        $(
+            impl PackedValue for $ty {
+                #[inline(always)]
+                fn into_word(self) -> usize {
+                    self as usize
+                }
+
+                #[inline(always)]
+                fn from_word(word: usize) -> Self {
+                    word as $ty
+                }
+            }
+        )+
+    };
+}
+
+packed_value!(u8, u16, u32, usize);
+
+impl PackedValue for bool {
+    #[inline(always)]
+    fn into_word(self) -> usize {
+        self as usize
+    }
+
+    #[inline(always)]
+    fn from_word(word: usize) -> Self {
+        word != 0
+    }
+}
+
+impl PackedValue for () {
+    #[inline(always)]
+    fn into_word(self) -> usize {
+        0
+    }
+
+    #[inline(always)]
+    fn from_word(_: usize) -> Self {}
+}
+
+/// A `LinuxResult<T>` packed into one word: `Ok` values as is and errors as
+/// their negated codes.
+///
+/// Packing and the checks are branch-free; only [`unpack`](Self::unpack)
+/// branches, on the final match.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{packed::PackedResult, LinuxError};
+/// #
+/// let res = PackedResult::<u32>::pack(Ok(7));
+/// assert_eq!(res.raw(), 7);
+/// assert_eq!(res.unpack(), Ok(7));
+///
+/// let res = PackedResult::<u32>::err(LinuxError::EAGAIN);
+/// assert_eq!(res.raw() as isize, -11);
+/// assert_eq!(res.unpack(), Err(LinuxError::EAGAIN));
+/// assert_eq!(core::mem::size_of_val(&res), core::mem::size_of::<usize>());
+/// ```
+#[repr(transparent)]
+pub struct PackedResult<T: PackedValue = usize> {
+    word: usize,
+    _value: PhantomData<T>,
+}
+
+impl<T: PackedValue> PackedResult<T> {
+    /// Packs a successful value.
+    #[inline(always)]
+    pub fn ok(value: T) -> Self {
+        Self::from_raw(value.into_word())
+    }
+
+    /// Packs an error.
+    #[inline(always)]
+    pub const fn err(err: LinuxError) -> Self {
+        Self::from_raw((err.code() as usize).wrapping_neg())
+    }
+
+    /// Packs a [`LinuxResult`].
+    #[inline(always)]
+    pub fn pack(res: LinuxResult<T>) -> Self {
+        match res {
+            Ok(value) => Self::ok(value),
+            Err(err) => Self::err(err),
+        }
+    }
+
+    /// Wraps a raw word, e.g. as returned by an assembly stub.
+    #[inline(always)]
+    pub const fn from_raw(word: usize) -> Self {
+        Self {
+            word,
+            _value: PhantomData,
+        }
+    }
+
+    /// Returns the raw word.
+    #[inline(always)]
+    pub const fn raw(self) -> usize {
+        self.word
+    }
+
+    /// Returns `true` if the result is an error.
+    #[inline(always)]
+    pub const fn is_err(self) -> bool {
+        self.word > usize::MAX - MAX_ERRNO
+    }
+
+    /// Returns `true` if the result is a successful value.
+    #[inline(always)]
+    pub const fn is_ok(self) -> bool {
+        !self.is_err()
+    }
+
+    /// Returns the successful value, or `default` on error.
+    #[inline(always)]
+    pub fn unwrap_or(self, default: T) -> T {
+        let mask = (self.is_err() as usize).wrapping_neg();
+        T::from_word((self.word & !mask) | (default.into_word() & mask))
+    }
+
+    /// Unpacks into a [`LinuxResult`].
+    #[inline(always)]
+    pub fn unpack(self) -> LinuxResult<T> {
+        if self.is_err() {
+            // Every code in the error range is a valid `LinuxError`.
+            Err(LinuxError::new(self.word.wrapping_neg() as i32).unwrap_or(LinuxError::EIO))
+        } else {
+            Ok(T::from_word(self.word))
+        }
+    }
+}
+
+impl<T: PackedValue> Clone for PackedResult<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PackedValue> Copy for PackedResult<T> {}
+
+impl<T: PackedValue> PartialEq for PackedResult<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.word == other.word
+    }
+}
+
+impl<T: PackedValue> Eq for PackedResult<T> {}
+
+impl<T: PackedValue> From<LinuxResult<T>> for PackedResult<T> {
+    fn from(res: LinuxResult<T>) -> Self {
+        Self::pack(res)
+    }
+}
+
+impl<T: PackedValue> From<AxResult<T>> for PackedResult<T> {
+    fn from(res: AxResult<T>) -> Self {
+        Self::pack(res.map_err(LinuxError::from))
+    }
+}
+
+impl<T: PackedValue> From<PackedResult<T>> for LinuxResult<T> {
+    fn from(res: PackedResult<T>) -> Self {
+        res.unpack()
+    }
+}
+
+impl<T: PackedValue + fmt::Debug> fmt::Debug for PackedResult<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unpack() {
+            Ok(value) => write!(f, "Ok({value:?})"),
+            Err(err) => write!(f, "Err({err:?})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_result() {
+        let ok = PackedResult::<u8>::ok(200);
+        assert!(ok.is_ok());
+        assert_eq!(ok.unpack(), Ok(200));
+        assert_eq!(ok.unwrap_or(1), 200);
+
+        let err = PackedResult::<bool>::pack(Err(LinuxError::ENOMEM));
+        assert!(err.is_err());
+        assert_eq!(err.raw() as isize, -12);
+        assert!(err.unwrap_or(true));
+        assert_eq!(LinuxResult::from(err), Err(LinuxError::ENOMEM));
+
+        assert_eq!(PackedResult::<()>::ok(()).raw(), 0);
+        let res: AxResult<u16> = Err(crate::AxError::NotFound);
+        assert_eq!(PackedResult::from(res).unpack(), Err(LinuxError::ENOENT));
+        assert!(PackedResult::<usize>::from_raw(MAX_ERRNO.wrapping_neg()).is_err());
+        assert!(PackedResult::<usize>::from_raw((MAX_ERRNO + 1).wrapping_neg()).is_ok());
+        let unnamed = LinuxError::new(4000).unwrap();
+        assert_eq!(PackedResult::<u32>::err(unnamed).unpack(), Err(unnamed));
+    }
+}
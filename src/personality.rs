@@ -0,0 +1,193 @@
+//! Per-task translation of errors into a numeric errno space.
+//!
+//! The kernel keeps a `&'static dyn ErrnoTranslator` per task and applies it
+//! at the syscall boundary with [`syscall_return`], so tasks with different
+//! personalities (Linux, a FreeBSD compatibility layer, ...) see the error
+//! numbers of their own ABI.
+
+use crate::{AxError, AxResult, LinuxError};
+
+const COUNT: usize = core::mem::variant_count::<AxError>();
+
+/// Translates [`AxError`]s into the error numbers of an ABI.
+pub trait ErrnoTranslator: Sync {
+    /// Returns the name of the personality.
+    fn name(&self) -> &str;
+
+    /// Returns the positive error number reported for `err`.
+    fn errno(&self, err: AxError) -> i32;
+}
+
+/// The Linux personality, translating with `From<AxError> for LinuxError`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinuxAbi;
+
+impl ErrnoTranslator for LinuxAbi {
+    fn name(&self) -> &str {
+        "linux"
+    }
+
+    fn errno(&self, err: AxError) -> i32 {
+        LinuxError::from(err).code()
+    }
+}
+
+/// A translation table with an entry per [`AxError`].
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::personality::{ErrnoTable, ErrnoTranslator};
+/// # use axerrno::AxError;
+/// #
+/// static STRICT: ErrnoTable = ErrnoTable::new("strict", 5)
+///     .with(AxError::NotFound, 2)
+///     .with(AxError::Unsupported, 95);
+///
+/// assert_eq!(STRICT.errno(AxError::Unsupported), 95);
+/// assert_eq!(STRICT.errno(AxError::BadState), 5);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ErrnoTable {
+    name: &'static str,
+    codes: [i32; COUNT],
+}
+
+impl ErrnoTable {
+    /// Creates a table translating every error to `default`.
+    pub const fn new(name: &'static str, default: i32) -> Self {
+        Self {
+            name,
+            codes: [default; COUNT],
+        }
+    }
+
+    /// Returns the table with `err` translated to `errno`.
+    pub const fn with(mut self, err: AxError, errno: i32) -> Self {
+        self.codes[err.code() as usize - 1] = errno;
+        self
+    }
+
+    /// Error numbers of FreeBSD, for the FreeBSD compatibility layer.
+    pub const FREEBSD: Self = Self::new("freebsd", 5)
+        .with(AxError::AddrInUse, 48)
+        .with(AxError::AlreadyExists, 17)
+        .with(AxError::BadAddress, 14)
+        .with(AxError::BadState, 14)
+        .with(AxError::ConnectionRefused, 61)
+        .with(AxError::ConnectionReset, 54)
+        .with(AxError::DirectoryNotEmpty, 66)
+        .with(AxError::InvalidData, 22)
+        .with(AxError::InvalidInput, 22)
+        .with(AxError::IsADirectory, 21)
+        .with(AxError::NoMemory, 12)
+        .with(AxError::NotADirectory, 20)
+        .with(AxError::NotConnected, 57)
+        .with(AxError::NotFound, 2)
+        .with(AxError::PermissionDenied, 13)
+        .with(AxError::ResourceBusy, 16)
+        .with(AxError::StorageFull, 28)
+        .with(AxError::Unsupported, 78)
+        .with(AxError::WouldBlock, 35)
+        .with(AxError::Interrupted, 4)
+        .with(AxError::Timeout, 60)
+        .with(AxError::Again, 35)
+        .with(AxError::Busy, 16)
+        .with(AxError::FilesystemCorrupted, 97)
+        .with(AxError::ReadOnlyFilesystem, 30)
+        .with(AxError::InvalidExecutable, 8)
+        .with(AxError::NotSupported, 45)
+        .with(AxError::OperationNotPermitted, 1)
+        .with(AxError::NoSuchProcess, 3)
+        .with(AxError::NoSuchDeviceOrAddress, 6)
+        .with(AxError::ArgumentListTooLong, 7)
+        .with(AxError::BadFileDescriptor, 9)
+        .with(AxError::NoChildProcess, 10)
+        .with(AxError::NotABlockDevice, 15)
+        .with(AxError::CrossesDevices, 18)
+        .with(AxError::NoSuchDevice, 19)
+        .with(AxError::FileTableOverflow, 23)
+        .with(AxError::TooManyOpenFiles, 24)
+        .with(AxError::NotATty, 25)
+        .with(AxError::ExecutableFileBusy, 26)
+        .with(AxError::FileTooLarge, 27)
+        .with(AxError::NotSeekable, 29)
+        .with(AxError::TooManyLinks, 31)
+        .with(AxError::BrokenPipe, 32)
+        .with(AxError::OutOfRange, 34)
+        .with(AxError::ArgumentOutOfDomain, 33)
+        .with(AxError::Deadlock, 11)
+        .with(AxError::NameTooLong, 63)
+        .with(AxError::NoLocksAvailable, 77)
+        .with(AxError::FilesystemLoop, 62)
+        .with(AxError::NoMessage, 83)
+        .with(AxError::IdentifierRemoved, 82)
+        .with(AxError::NoData, 87)
+        .with(AxError::ProtocolError, 92)
+        .with(AxError::BadMessage, 89)
+        .with(AxError::Overflow, 84)
+        .with(AxError::IllegalByteSequence, 86)
+        .with(AxError::NotASocket, 38)
+        .with(AxError::DestinationAddressRequired, 39)
+        .with(AxError::MessageTooLong, 40)
+        .with(AxError::WrongProtocolType, 41)
+        .with(AxError::ProtocolOptionUnavailable, 42)
+        .with(AxError::ProtocolNotSupported, 43)
+        .with(AxError::SocketTypeNotSupported, 44)
+        .with(AxError::AddressFamilyNotSupported, 47)
+        .with(AxError::AddrNotAvailable, 49)
+        .with(AxError::NetworkDown, 50)
+        .with(AxError::NetworkUnreachable, 51)
+        .with(AxError::ConnectionAborted, 53)
+        .with(AxError::NoBufferSpace, 55)
+        .with(AxError::AlreadyConnected, 56)
+        .with(AxError::EndpointShutdown, 58)
+        .with(AxError::ConnectionTimedOut, 60)
+        .with(AxError::HostDown, 64)
+        .with(AxError::HostUnreachable, 65)
+        .with(AxError::AlreadyInProgress, 37)
+        .with(AxError::InProgress, 36)
+        .with(AxError::StaleNetworkFileHandle, 70)
+        .with(AxError::QuotaExceeded, 69)
+        .with(AxError::NoMedium, 6)
+        .with(AxError::Canceled, 85)
+        .with(AxError::LinkSevered, 91)
+        .with(AxError::OwnerDead, 96)
+        .with(AxError::NotRecoverable, 95);
+}
+
+impl ErrnoTranslator for ErrnoTable {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn errno(&self, err: AxError) -> i32 {
+        self.codes[err.code() as usize - 1]
+    }
+}
+
+/// Encodes a syscall result as a raw return value, translating the error
+/// with `personality`.
+pub fn syscall_return(personality: &dyn ErrnoTranslator, res: AxResult<usize>) -> isize {
+    match res {
+        Ok(v) => v as isize,
+        Err(e) => -(personality.errno(e) as isize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_personality() {
+        assert_eq!(syscall_return(&LinuxAbi, Err(AxError::WouldBlock)), -11);
+        assert_eq!(
+            syscall_return(&ErrnoTable::FREEBSD, Err(AxError::WouldBlock)),
+            -35
+        );
+        assert_eq!(syscall_return(&ErrnoTable::FREEBSD, Ok(4)), 4);
+        assert_eq!(ErrnoTable::FREEBSD.errno(AxError::Io), 5);
+        assert_eq!(ErrnoTable::FREEBSD.name(), "freebsd");
+    }
+}
@@ -0,0 +1,22 @@
+//! The commonly used types, extension traits and macros, for a single glob
+//! import.
+//!
+//! ```
+//! use axerrno::prelude::*;
+//!
+//! fn parse(fd: i32) -> AxResult<i32> {
+//!     ensure!(fd >= 0, ax_err!(BadFileDescriptor));
+//!     Ok(fd)
+//! }
+//!
+//! assert_eq!(parse(-1).into_linux(), Err(LinuxError::EBADF));
+//! ```
+
+pub use crate::{
+    ax_err, ax_err_once, ax_err_ratelimited, ax_err_type, ax_try, bail_if, ensure, ensure_eq,
+    ensure_ne, errno_match, errno_set, expect_ok, fault_point, ok_or_ax, one_of, syscall_result,
+};
+pub use crate::{
+    AxError, AxErrorAt, AxResult, AxResultAt, Errno, ErrorTrace, LinuxError, LinuxResult,
+};
+pub use crate::{AxResultExt, LinuxResultExt, OptionExt, PollExt, TraceExt};
@@ -0,0 +1,72 @@
+//! ANSI-colored rendering of errors for the debug console.
+//!
+//! [`AxError::pretty`] and [`LinuxError::pretty`] return a wrapper whose
+//! `Display` implementation highlights the error name, which makes errors
+//! stand out when scanning serial logs.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+const NAME: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// An error rendered with ANSI colors by its `Display` implementation.
+///
+/// Created by [`AxError::pretty`] and [`LinuxError::pretty`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pretty<T>(T);
+
+impl<T> Pretty<T> {
+    /// Returns the wrapped error.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl AxError {
+    /// Returns a wrapper displaying the error name in bold red followed by
+    /// its description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::AxError;
+    /// #
+    /// assert_eq!(
+    ///     AxError::NotFound.pretty().to_string(),
+    ///     "\x1b[1;31mNotFound\x1b[0m: Entity not found",
+    /// );
+    /// ```
+    pub const fn pretty(self) -> Pretty<Self> {
+        Pretty(self)
+    }
+}
+
+impl LinuxError {
+    /// Returns a wrapper displaying the errno name in bold red followed by
+    /// its message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::LinuxError;
+    /// #
+    /// println!("open: {}", LinuxError::EACCES.pretty());
+    /// ```
+    pub const fn pretty(self) -> Pretty<Self> {
+        Pretty(self)
+    }
+}
+
+impl fmt::Display for Pretty<AxError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{NAME}{:?}{RESET}: {}", self.0, self.0)
+    }
+}
+
+impl fmt::Display for Pretty<LinuxError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{NAME}{:?}{RESET}: {}", self.0, self.0)
+    }
+}
@@ -0,0 +1,106 @@
+//! Rate limiting of error warnings, as used by [`ax_err_ratelimited!`].
+//!
+//! [`ax_err_ratelimited!`]: crate::ax_err_ratelimited
+
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use core::time::Duration;
+
+use crate::clock;
+
+/// The default interval of [`ax_err_ratelimited!`], same as Linux's
+/// `DEFAULT_RATELIMIT_INTERVAL`.
+///
+/// [`ax_err_ratelimited!`]: crate::ax_err_ratelimited
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The default burst of [`ax_err_ratelimited!`], same as Linux's
+/// `DEFAULT_RATELIMIT_BURST`.
+///
+/// [`ax_err_ratelimited!`]: crate::ax_err_ratelimited
+pub const DEFAULT_BURST: u32 = 10;
+
+/// A token bucket that holds `burst` tokens and is refilled every `interval`,
+/// like Linux's `struct ratelimit_state`.
+///
+/// The time is read from [`clock::now`]. Without an installed clock, the
+/// bucket is never refilled, so at most `burst` events pass in total.
+#[derive(Debug)]
+pub struct RateLimit {
+    interval_ns: u64,
+    burst: u32,
+    /// The start of the current interval in nanoseconds, plus one so that
+    /// zero means "not started".
+    begin_ns: AtomicU64,
+    passed: AtomicU32,
+    missed: AtomicU32,
+}
+
+impl RateLimit {
+    /// Constructs a rate limit allowing `burst` events per `interval`.
+    pub const fn new(interval: Duration, burst: u32) -> Self {
+        Self {
+            interval_ns: interval.as_nanos() as u64,
+            burst,
+            begin_ns: AtomicU64::new(0),
+            passed: AtomicU32::new(0),
+            missed: AtomicU32::new(0),
+        }
+    }
+
+    /// Takes a token at the current time of the installed clock.
+    ///
+    /// See [`check_at`](Self::check_at).
+    pub fn check(&self) -> Option<u32> {
+        match clock::now() {
+            Some(now) => self.check_at(now),
+            None => self.take(),
+        }
+    }
+
+    /// Takes a token at time `now`.
+    ///
+    /// Returns `None` if the event must be suppressed, or the number of events
+    /// suppressed since the previous event that passed.
+    pub fn check_at(&self, now: Duration) -> Option<u32> {
+        let now_ns = now.as_nanos() as u64 + 1;
+        let begin_ns = self.begin_ns.load(Ordering::Relaxed);
+        let expired = begin_ns == 0 || now_ns.saturating_sub(begin_ns) >= self.interval_ns;
+        if expired
+            && self
+                .begin_ns
+                .compare_exchange(begin_ns, now_ns, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            self.passed.store(0, Ordering::Relaxed);
+        }
+        self.take()
+    }
+
+    fn take(&self) -> Option<u32> {
+        if self.passed.fetch_add(1, Ordering::Relaxed) < self.burst {
+            Some(self.missed.swap(0, Ordering::Relaxed))
+        } else {
+            self.passed.store(self.burst, Ordering::Relaxed);
+            self.missed.fetch_add(1, Ordering::Relaxed);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratelimit() {
+        let limit = RateLimit::new(Duration::from_secs(1), 2);
+        let at = Duration::from_millis;
+        assert_eq!(limit.check_at(at(0)), Some(0));
+        assert_eq!(limit.check_at(at(10)), Some(0));
+        assert_eq!(limit.check_at(at(20)), None);
+        assert_eq!(limit.check_at(at(999)), None);
+        assert_eq!(limit.check_at(at(1000)), Some(2));
+        assert_eq!(limit.check_at(at(1001)), Some(0));
+        assert_eq!(limit.check_at(at(1002)), None);
+    }
+}
@@ -0,0 +1,41 @@
+//! Raw error numbers as plain `i32` constants.
+//!
+//! Each constant has the same value as the [`LinuxError`] constant of the same
+//! name. They are meant for FFI signatures, assembly stubs and matching on raw
+//! register values, where constructing a [`LinuxError`] is unnecessary.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::{raw, LinuxError};
+//!
+//! fn is_missing(ret: isize) -> bool {
+//!     matches!((-ret) as i32, raw::ENOENT | raw::ENOTDIR)
+//! }
+//! assert!(is_missing(-2));
+//! assert_eq!(raw::EAGAIN, LinuxError::EAGAIN.code());
+//! ```
+//!
+//! [`LinuxError`]: crate::LinuxError
+
+include!(concat!(env!("OUT_DIR"), "/raw_errno.rs"));
+
+/// Alias of [`EAGAIN`].
+pub const EWOULDBLOCK: i32 = EAGAIN;
+/// Alias of [`EDEADLK`].
+pub const EDEADLOCK: i32 = EDEADLK;
+/// Alias of [`EOPNOTSUPP`].
+pub const ENOTSUP: i32 = EOPNOTSUPP;
+
+#[cfg(test)]
+mod tests {
+    use crate::LinuxError;
+
+    #[test]
+    fn test_raw_matches_enum() {
+        assert_eq!(super::EPERM, LinuxError::EPERM.code());
+        assert_eq!(super::EHWPOISON, LinuxError::EHWPOISON.code());
+        assert_eq!(super::ERESTARTSYS, LinuxError::ERESTARTSYS.code());
+        assert_eq!(super::ENOTSUP, LinuxError::ENOTSUP.code());
+    }
+}
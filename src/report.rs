@@ -0,0 +1,220 @@
+//! Structured error reports handed to user space.
+//!
+//! An [`ErrorReport`] is encoded into a user buffer by
+//! [`ErrorReport::encode_into`] and decoded by [`ErrorReport::decode`]. All
+//! integers are little-endian:
+//!
+//! | Offset | Size | Field                                            |
+//! |--------|------|--------------------------------------------------|
+//! | 0      | 1    | format version ([`REPORT_VERSION`])              |
+//! | 1      | 1    | flags: bit 0 message present, bit 1 location present |
+//! | 2      | 2    | subsystem                                        |
+//! | 4      | 4    | error code                                       |
+//! | 8      | 4    | line                                             |
+//! | 12     | 4    | column                                           |
+//! | 16     | 2    | file name length `F`                             |
+//! | 18     | 2    | message length `M`                               |
+//! | 20     | `F`  | file name, UTF-8                                 |
+//! | 20+`F` | `M`  | message, UTF-8                                   |
+//!
+//! Line, column and file name are zero when no location is present.
+
+use core::panic::Location;
+
+use crate::subsystem::Subsystem;
+use crate::{LinuxError, LinuxResult};
+
+/// The version of the binary format written by [`ErrorReport::encode_into`].
+pub const REPORT_VERSION: u8 = 1;
+
+/// The size in bytes of the fixed header of an encoded report.
+pub const REPORT_HEADER_SIZE: usize = 20;
+
+const FLAG_MESSAGE: u8 = 1 << 0;
+const FLAG_LOCATION: u8 = 1 << 1;
+
+/// The source location an error was raised at.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReportLocation<'a> {
+    /// The source file name.
+    pub file: &'a str,
+    /// The line number, starting from 1.
+    pub line: u32,
+    /// The column number, starting from 1.
+    pub column: u32,
+}
+
+impl From<&'static Location<'static>> for ReportLocation<'static> {
+    fn from(loc: &'static Location<'static>) -> Self {
+        Self {
+            file: loc.file(),
+            line: loc.line(),
+            column: loc.column(),
+        }
+    }
+}
+
+/// A detailed description of an error.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{report::ErrorReport, subsystem::Subsystem, LinuxError};
+/// #
+/// let report = ErrorReport {
+///     code: LinuxError::EROFS,
+///     subsystem: Subsystem::FS,
+///     message: Some("journal replay required"),
+///     location: None,
+/// };
+/// let mut buf = [0; 64];
+/// let len = report.encode_into(&mut buf).unwrap();
+/// assert_eq!(ErrorReport::decode(&buf[..len]), Ok(report));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorReport<'a> {
+    /// The error code.
+    pub code: LinuxError,
+    /// The subsystem that raised the error.
+    pub subsystem: Subsystem,
+    /// An optional human readable message.
+    pub message: Option<&'a str>,
+    /// The source location the error was raised at, if known.
+    pub location: Option<ReportLocation<'a>>,
+}
+
+impl<'a> ErrorReport<'a> {
+    /// Constructs a report with the location of the caller.
+    #[track_caller]
+    pub fn here(code: LinuxError, subsystem: Subsystem, message: Option<&'a str>) -> Self {
+        Self {
+            code,
+            subsystem,
+            message,
+            location: Some(Location::caller().into()),
+        }
+    }
+
+    /// Returns the number of bytes [`encode_into`](Self::encode_into) writes.
+    pub fn encoded_len(&self) -> usize {
+        REPORT_HEADER_SIZE + self.file().len() + self.message().len()
+    }
+
+    /// Encodes the report into `buf` and returns the number of bytes written.
+    ///
+    /// Strings longer than `u16::MAX` bytes are truncated. Returns
+    /// [`LinuxError::ERANGE`] if `buf` is too small, in which case
+    /// [`encoded_len`](Self::encoded_len) tells the required size.
+    pub fn encode_into(&self, buf: &mut [u8]) -> LinuxResult<usize> {
+        let (file, message) = (self.file(), self.message());
+        let len = self.encoded_len();
+        if buf.len() < len {
+            return Err(LinuxError::ERANGE);
+        }
+
+        let mut flags = 0;
+        if self.message.is_some() {
+            flags |= FLAG_MESSAGE;
+        }
+        let (line, column) = match self.location {
+            Some(loc) => {
+                flags |= FLAG_LOCATION;
+                (loc.line, loc.column)
+            }
+            None => (0, 0),
+        };
+
+        buf[0] = REPORT_VERSION;
+        buf[1] = flags;
+        buf[2..4].copy_from_slice(&self.subsystem.0.to_le_bytes());
+        buf[4..8].copy_from_slice(&self.code.code().to_le_bytes());
+        buf[8..12].copy_from_slice(&line.to_le_bytes());
+        buf[12..16].copy_from_slice(&column.to_le_bytes());
+        buf[16..18].copy_from_slice(&(file.len() as u16).to_le_bytes());
+        buf[18..20].copy_from_slice(&(message.len() as u16).to_le_bytes());
+        let (file_buf, rest) = buf[REPORT_HEADER_SIZE..].split_at_mut(file.len());
+        file_buf.copy_from_slice(file.as_bytes());
+        rest[..message.len()].copy_from_slice(message.as_bytes());
+        Ok(len)
+    }
+
+    /// Decodes a report previously encoded by
+    /// [`encode_into`](Self::encode_into).
+    ///
+    /// Returns [`LinuxError::EINVAL`] if `buf` does not hold a valid report.
+    pub fn decode(buf: &'a [u8]) -> LinuxResult<Self> {
+        let header = buf.get(..REPORT_HEADER_SIZE).ok_or(LinuxError::EINVAL)?;
+        let u16_at = |i: usize| u16::from_le_bytes([header[i], header[i + 1]]);
+        let u32_at = |i: usize| u32::from_le_bytes(header[i..i + 4].try_into().unwrap());
+
+        if header[0] != REPORT_VERSION {
+            return Err(LinuxError::EINVAL);
+        }
+        let flags = header[1];
+        let code = LinuxError::try_from(u32_at(4) as i32).map_err(|_| LinuxError::EINVAL)?;
+        let file_len = u16_at(16) as usize;
+        let message_len = u16_at(18) as usize;
+
+        let body = &buf[REPORT_HEADER_SIZE..];
+        if body.len() < file_len + message_len {
+            return Err(LinuxError::EINVAL);
+        }
+        let str_at = |bytes| core::str::from_utf8(bytes).map_err(|_| LinuxError::EINVAL);
+        let file = str_at(&body[..file_len])?;
+        let message = str_at(&body[file_len..file_len + message_len])?;
+
+        Ok(Self {
+            code,
+            subsystem: Subsystem(u16_at(2)),
+            message: (flags & FLAG_MESSAGE != 0).then_some(message),
+            location: (flags & FLAG_LOCATION != 0).then_some(ReportLocation {
+                file,
+                line: u32_at(8),
+                column: u32_at(12),
+            }),
+        })
+    }
+
+    fn file(&self) -> &'a str {
+        truncate(self.location.map_or("", |loc| loc.file))
+    }
+
+    fn message(&self) -> &'a str {
+        truncate(self.message.unwrap_or(""))
+    }
+}
+
+/// Truncates `s` to at most `u16::MAX` bytes on a character boundary.
+fn truncate(s: &str) -> &str {
+    let mut end = s.len().min(u16::MAX as usize);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = ErrorReport::here(LinuxError::ENOENT, Subsystem::FS, Some("no such inode"));
+        let mut buf = [0; 128];
+        let len = report.encode_into(&mut buf).unwrap();
+        assert_eq!(len, report.encoded_len());
+        assert_eq!(ErrorReport::decode(&buf[..len]), Ok(report));
+        assert_eq!(report.location.unwrap().file, file!());
+
+        assert_eq!(
+            report.encode_into(&mut buf[..len - 1]),
+            Err(LinuxError::ERANGE)
+        );
+        assert_eq!(
+            ErrorReport::decode(&buf[..len - 1]),
+            Err(LinuxError::EINVAL)
+        );
+        buf[0] = REPORT_VERSION + 1;
+        assert_eq!(ErrorReport::decode(&buf[..len]), Err(LinuxError::EINVAL));
+    }
+}
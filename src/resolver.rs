@@ -0,0 +1,150 @@
+//! Conversions between `getaddrinfo(3)` resolver errors and error codes.
+//!
+//! [`EaiError`] holds the `EAI_*` codes returned by `getaddrinfo` and
+//! `getnameinfo`, with the values of glibc and musl. It is used by the
+//! user-space resolver and the libc-compatible `getaddrinfo`.
+
+use core::fmt;
+
+use crate::AxError;
+
+macro_rules! eai_errors {
+    ($($name: ident = $value: literal => $err: ident, $msg: literal;)*) => {
+        impl EaiError {
+            $(
+                #[doc = concat!("`", stringify!($name), "`: ", $msg, ".")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the name of a known code.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the message of the code, as returned by
+            /// `gai_strerror(3)`.
+            pub const fn message(self) -> &'static str {
+                match self {
+                    $(Self::$name => $msg,)*
+                    _ => "Unknown error",
+                }
+            }
+
+            fn ax_error(self) -> AxError {
+                use AxError::*;
+                match self {
+                    $(Self::$name => $err,)*
+                    _ => Io,
+                }
+            }
+        }
+    };
+}
+
+/// A `getaddrinfo(3)` resolver error.
+///
+/// [`EAI_SYSTEM`](Self::EAI_SYSTEM) means that the cause is in `errno`, which
+/// is not part of the code; it converts to [`Io`](AxError::Io).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{resolver::EaiError, AxError};
+/// #
+/// assert_eq!(AxError::from(EaiError::EAI_NONAME), AxError::NotFound);
+/// assert_eq!(EaiError::from(AxError::NoMemory), EaiError::EAI_MEMORY);
+/// assert_eq!(EaiError::EAI_AGAIN.message(), "Temporary failure in name resolution");
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct EaiError(pub i32);
+
+eai_errors! {
+    EAI_BADFLAGS = -1 => InvalidInput, "Bad value for ai_flags";
+    EAI_NONAME = -2 => NotFound, "Name or service not known";
+    EAI_AGAIN = -3 => Again, "Temporary failure in name resolution";
+    EAI_FAIL = -4 => Io, "Non-recoverable failure in name resolution";
+    EAI_NODATA = -5 => NotFound, "No address associated with hostname";
+    EAI_FAMILY = -6 => AddressFamilyNotSupported, "ai_family not supported";
+    EAI_SOCKTYPE = -7 => SocketTypeNotSupported, "ai_socktype not supported";
+    EAI_SERVICE = -8 => NotFound, "Servname not supported for ai_socktype";
+    EAI_ADDRFAMILY = -9 => NotFound, "Address family for hostname not supported";
+    EAI_MEMORY = -10 => NoMemory, "Memory allocation failure";
+    EAI_SYSTEM = -11 => Io, "System error";
+    EAI_OVERFLOW = -12 => InvalidInput, "Argument buffer overflow";
+}
+
+impl EaiError {
+    /// Decodes the return value of `getaddrinfo`, which is 0 on success.
+    pub const fn from_ret(ret: i32) -> Result<(), Self> {
+        match ret {
+            0 => Ok(()),
+            code => Err(Self(code)),
+        }
+    }
+}
+
+impl From<EaiError> for AxError {
+    /// Unknown codes become [`Io`](AxError::Io).
+    fn from(e: EaiError) -> Self {
+        e.ax_error()
+    }
+}
+
+impl From<AxError> for EaiError {
+    fn from(e: AxError) -> Self {
+        use AxError::*;
+        match e {
+            NotFound => Self::EAI_NONAME,
+            Again | WouldBlock | Timeout | Interrupted => Self::EAI_AGAIN,
+            NoMemory => Self::EAI_MEMORY,
+            InvalidInput => Self::EAI_BADFLAGS,
+            AddressFamilyNotSupported => Self::EAI_FAMILY,
+            SocketTypeNotSupported => Self::EAI_SOCKTYPE,
+            _ => Self::EAI_FAIL,
+        }
+    }
+}
+
+impl fmt::Debug for EaiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "EaiError({})", self.0),
+        }
+    }
+}
+
+impl fmt::Display for EaiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eai_error() {
+        assert_eq!(EaiError::from_ret(0), Ok(()));
+        assert_eq!(EaiError::from_ret(-4), Err(EaiError::EAI_FAIL));
+        assert_eq!(EaiError(-100).name(), None);
+        assert_eq!(AxError::from(EaiError(-100)), AxError::Io);
+
+        for e in [
+            AxError::NotFound,
+            AxError::Again,
+            AxError::NoMemory,
+            AxError::InvalidInput,
+            AxError::AddressFamilyNotSupported,
+            AxError::SocketTypeNotSupported,
+        ] {
+            assert_eq!(AxError::from(EaiError::from(e)), e);
+        }
+        assert_eq!(EaiError::from(AxError::Io), EaiError::EAI_FAIL);
+    }
+}
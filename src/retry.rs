@@ -0,0 +1,281 @@
+//! Helpers for retrying operations interrupted by signals or failing
+//! transiently.
+//!
+//! With the `async` feature, [`retry_backoff`] retries an asynchronous
+//! operation with exponential backoff.
+
+#[cfg(feature = "async")]
+use core::future::Future;
+#[cfg(feature = "async")]
+use core::time::Duration;
+
+use crate::{AxError, LinuxError};
+
+impl LinuxError {
+    /// Returns `true` if the operation may succeed when retried:
+    /// [`EAGAIN`](Self::EAGAIN), [`EINTR`](Self::EINTR),
+    /// [`ETIMEDOUT`](Self::ETIMEDOUT) and [`ETIME`](Self::ETIME), the errno of
+    /// [`AxError::Timeout`].
+    pub const fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::EAGAIN | Self::EINTR | Self::ETIMEDOUT | Self::ETIME
+        )
+    }
+}
+
+impl AxError {
+    /// Returns `true` if the operation may succeed when retried, i.e. if the
+    /// corresponding [`LinuxError`] is
+    /// [retryable](LinuxError::is_retryable).
+    pub fn is_retryable(self) -> bool {
+        LinuxError::from(self).is_retryable()
+    }
+}
+
+/// Calls `f` until it returns something other than an
+/// [`Interrupted`](crate::AxError::Interrupted) / [`EINTR`](LinuxError::EINTR)
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{retry::retry_on_eintr, AxError, AxResult};
+/// #
+/// let mut calls = 0;
+/// let res: AxResult<i32> = retry_on_eintr(|| {
+///     calls += 1;
+///     if calls < 3 { Err(AxError::Interrupted) } else { Ok(calls) }
+/// });
+/// assert_eq!(res, Ok(3));
+/// ```
+pub fn retry_on_eintr<T, E, F>(mut f: F) -> Result<T, E>
+where
+    E: Copy + Into<LinuxError>,
+    F: FnMut() -> Result<T, E>,
+{
+    loop {
+        match f() {
+            Err(e) if e.into() == LinuxError::EINTR => continue,
+            res => return res,
+        }
+    }
+}
+
+/// Like [`retry_on_eintr`], but calls `f` at most `max_attempts` times.
+///
+/// The interrupted error of the last attempt is returned if all attempts are
+/// interrupted. `f` is called at least once.
+pub fn retry_on_eintr_n<T, E, F>(max_attempts: usize, mut f: F) -> Result<T, E>
+where
+    E: Copy + Into<LinuxError>,
+    F: FnMut() -> Result<T, E>,
+{
+    let mut attempts = 1;
+    loop {
+        match f() {
+            Err(e) if e.into() == LinuxError::EINTR && attempts < max_attempts => attempts += 1,
+            res => return res,
+        }
+    }
+}
+
+/// Extension methods form of [`retry_on_eintr`] and [`retry_on_eintr_n`].
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{retry::RetryExt, LinuxError, LinuxResult};
+/// #
+/// let op = || -> LinuxResult<usize> { Err(LinuxError::EINTR) };
+/// assert_eq!(op.retry_on_eintr_n(4), Err(LinuxError::EINTR));
+/// ```
+pub trait RetryExt<T, E> {
+    /// See [`retry_on_eintr`].
+    fn retry_on_eintr(self) -> Result<T, E>;
+
+    /// See [`retry_on_eintr_n`].
+    fn retry_on_eintr_n(self, max_attempts: usize) -> Result<T, E>;
+}
+
+impl<T, E, F> RetryExt<T, E> for F
+where
+    E: Copy + Into<LinuxError>,
+    F: FnMut() -> Result<T, E>,
+{
+    fn retry_on_eintr(self) -> Result<T, E> {
+        retry_on_eintr(self)
+    }
+
+    fn retry_on_eintr_n(self, max_attempts: usize) -> Result<T, E> {
+        retry_on_eintr_n(max_attempts, self)
+    }
+}
+
+/// Sleeps asynchronously, provided by the runtime to [`retry_backoff`].
+#[cfg(feature = "async")]
+pub trait Timer {
+    /// Returns a future completing after `duration`.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
+
+/// An exponential backoff policy for [`retry_backoff`].
+///
+/// The delay starts at `initial` and doubles after every attempt, up to
+/// `max_delay`.
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Backoff {
+    initial: Duration,
+    max_delay: Duration,
+    max_attempts: usize,
+}
+
+#[cfg(feature = "async")]
+impl Backoff {
+    /// Creates a policy calling the operation at most `max_attempts` times.
+    pub const fn new(initial: Duration, max_delay: Duration, max_attempts: usize) -> Self {
+        Self {
+            initial,
+            max_delay,
+            max_attempts,
+        }
+    }
+
+    /// Returns the delay after the `retry`-th failed attempt, starting at 0.
+    fn delay(&self, retry: usize) -> Duration {
+        let factor = 1u32.checked_shl(retry as u32).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Calls `f` until it succeeds, fails with an error that is not
+/// [retryable](LinuxError::is_retryable), or `policy` runs out of attempts,
+/// sleeping with `timer` between attempts.
+///
+/// The error of the last attempt is returned if all attempts fail. `f` is
+/// called at least once.
+///
+/// # Examples
+///
+/// ```
+/// # use core::cell::Cell;
+/// # use core::future::{ready, Future};
+/// # use core::pin::pin;
+/// # use core::task::{Context, Poll, Waker};
+/// # use core::time::Duration;
+/// # use axerrno::retry::{retry_backoff, Backoff, Timer};
+/// # use axerrno::{AxError, AxResult};
+/// #
+/// struct Sleep;
+///
+/// impl Timer for Sleep {
+///     fn sleep(&self, _: Duration) -> impl Future<Output = ()> {
+///         ready(())
+///     }
+/// }
+///
+/// let attempts = Cell::new(0);
+/// let connect = || async {
+///     attempts.set(attempts.get() + 1);
+///     if attempts.get() < 3 { Err(AxError::ConnectionTimedOut) } else { Ok(80) }
+/// };
+///
+/// let policy = Backoff::new(Duration::from_millis(10), Duration::from_secs(1), 5);
+/// let res: AxResult<u16> = match pin!(retry_backoff(&policy, &Sleep, connect))
+///     .poll(&mut Context::from_waker(Waker::noop()))
+/// {
+///     Poll::Ready(res) => res,
+///     Poll::Pending => unreachable!(),
+/// };
+/// assert_eq!(res, Ok(80));
+/// assert_eq!(attempts.get(), 3);
+/// ```
+#[cfg(feature = "async")]
+pub async fn retry_backoff<T, E, F, Fut>(
+    policy: &Backoff,
+    timer: &impl Timer,
+    mut f: F,
+) -> Result<T, E>
+where
+    E: Copy + Into<LinuxError>,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut retry = 0;
+    loop {
+        match f().await {
+            Err(e) if e.into().is_retryable() && retry + 1 < policy.max_attempts => {
+                timer.sleep(policy.delay(retry)).await;
+                retry += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxResult;
+
+    #[test]
+    fn test_retry_on_eintr() {
+        let mut calls = 0;
+        let res = retry_on_eintr_n(3, || -> AxResult {
+            calls += 1;
+            Err(AxError::Interrupted)
+        });
+        assert_eq!(res, Err(AxError::Interrupted));
+        assert_eq!(calls, 3);
+
+        calls = 0;
+        let res = (|| -> AxResult {
+            calls += 1;
+            Err(AxError::Again)
+        })
+        .retry_on_eintr();
+        assert_eq!(res, Err(AxError::Again));
+        assert_eq!(calls, 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_retry_backoff() {
+        use core::cell::{Cell, RefCell};
+        use core::future::ready;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        struct Recorder(RefCell<[Duration; 4]>, Cell<usize>);
+
+        impl Timer for Recorder {
+            fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+                self.0.borrow_mut()[self.1.get()] = duration;
+                self.1.set(self.1.get() + 1);
+                ready(())
+            }
+        }
+
+        let timer = Recorder(RefCell::new([Duration::ZERO; 4]), Cell::new(0));
+        let policy = Backoff::new(Duration::from_millis(10), Duration::from_millis(30), 4);
+        let calls = Cell::new(0);
+        let fut = pin!(retry_backoff(&policy, &timer, || {
+            calls.set(calls.get() + 1);
+            ready(AxResult::<()>::Err(AxError::Again))
+        }));
+        let res = fut.poll(&mut Context::from_waker(Waker::noop()));
+        assert_eq!(res, Poll::Ready(Err(AxError::Again)));
+        assert_eq!(calls.get(), 4);
+        let ms = Duration::from_millis;
+        assert_eq!(timer.0.borrow()[..3], [ms(10), ms(20), ms(30)]);
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(AxError::ConnectionTimedOut.is_retryable());
+        assert!(AxError::Timeout.is_retryable());
+        assert!(AxError::Interrupted.is_retryable());
+        assert!(!AxError::NotFound.is_retryable());
+    }
+}
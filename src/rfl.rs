@@ -0,0 +1,89 @@
+//! Conversions following the Rust-for-Linux `kernel::error` conventions.
+//!
+//! In Rust-for-Linux, an `Error` wraps a negative errno and is built with
+//! `Error::from_errno`, and C return values are checked with `to_result`.
+//! The functions here follow the same conventions, so a driver shared with
+//! Rust-for-Linux only needs a shim aliasing them to the `kernel` names.
+
+use core::ffi::c_int;
+
+use crate::{AxError, LinuxError, LinuxResult};
+
+impl LinuxError {
+    /// Converts a negative errno, as returned by C kernel functions, into a
+    /// `LinuxError`.
+    ///
+    /// Returns [`EINVAL`](Self::EINVAL) if `errno` is not in
+    /// `-MAX_ERRNO..=-1`, like `kernel::error::Error::from_errno`.
+    pub fn from_errno(errno: c_int) -> Self {
+        Self::try_from_errno(errno).unwrap_or(Self::EINVAL)
+    }
+
+    /// Converts a negative errno into a `LinuxError`, or returns `None` if it
+    /// is not in `-MAX_ERRNO..=-1`.
+    pub fn try_from_errno(errno: c_int) -> Option<Self> {
+        Self::new(errno.checked_neg()?)
+    }
+
+    /// Returns the negative errno, as returned by C kernel functions.
+    pub const fn to_errno(self) -> c_int {
+        -self.code()
+    }
+}
+
+impl AxError {
+    /// Returns the negative errno of the corresponding [`LinuxError`], as
+    /// returned by C kernel functions.
+    pub fn to_errno(self) -> c_int {
+        LinuxError::from(self).to_errno()
+    }
+}
+
+/// Converts a C return value into a [`LinuxResult`]: negative values are
+/// errors, others are success.
+///
+/// Negative values outside of `-MAX_ERRNO..=-1` become
+/// [`EINVAL`](LinuxError::EINVAL), like `kernel::error::to_result`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{rfl::to_result, LinuxError};
+/// #
+/// assert_eq!(to_result(0), Ok(()));
+/// assert_eq!(to_result(-12), Err(LinuxError::ENOMEM));
+/// assert_eq!(to_result(-5000), Err(LinuxError::EINVAL));
+/// ```
+pub fn to_result(ret: c_int) -> LinuxResult {
+    if ret < 0 {
+        Err(LinuxError::from_errno(ret))
+    } else {
+        Ok(())
+    }
+}
+
+/// Converts a [`LinuxResult`] into a C return value: `0` on success, the
+/// negative errno on failure.
+pub fn from_result(res: LinuxResult) -> c_int {
+    match res {
+        Ok(()) => 0,
+        Err(e) => e.to_errno(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfl_errno() {
+        assert_eq!(LinuxError::from_errno(-2), LinuxError::ENOENT);
+        assert_eq!(LinuxError::from_errno(2), LinuxError::EINVAL);
+        assert_eq!(LinuxError::from_errno(c_int::MIN), LinuxError::EINVAL);
+        assert_eq!(LinuxError::try_from_errno(-4096), None);
+        assert_eq!(AxError::NotFound.to_errno(), -2);
+        assert_eq!(to_result(7), Ok(()));
+        assert_eq!(from_result(to_result(-13)), -13);
+        assert_eq!(from_result(Ok(())), 0);
+    }
+}
@@ -0,0 +1,173 @@
+//! Conversions between Arm SCMI status codes and error codes.
+//!
+//! [`ScmiStatus`] mirrors the status values returned by the platform in SCMI
+//! (System Control and Management Interface) protocol messages, as used by
+//! clock, power domain, performance and sensor drivers.
+
+use core::fmt;
+
+use crate::{AxError, AxResult, LinuxError};
+
+macro_rules! scmi_status {
+    ($($name: ident = $value: literal => $err: ident, $errno: ident;)*) => {
+        impl ScmiStatus {
+            $(
+                #[doc = concat!("`", stringify!($name), "`.")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the name of a known status code.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    Self::SUCCESS => Some("SUCCESS"),
+                    $(Self::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the error code Linux's SCMI core reports for this
+            /// status, or `None` on success.
+            ///
+            /// Unknown codes become [`EIO`](LinuxError::EIO).
+            pub const fn linux_errno(self) -> Option<LinuxError> {
+                match self {
+                    Self::SUCCESS => None,
+                    $(Self::$name => Some(LinuxError::$errno),)*
+                    _ => Some(LinuxError::EIO),
+                }
+            }
+
+            fn ax_error(self) -> AxError {
+                use AxError::*;
+                match self {
+                    $(Self::$name => $err,)*
+                    _ => Io,
+                }
+            }
+        }
+    };
+}
+
+/// An SCMI protocol status code.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{scmi::ScmiStatus, AxError, LinuxError};
+/// #
+/// assert_eq!(ScmiStatus::DENIED.into_result(), Err(AxError::PermissionDenied));
+/// assert_eq!(ScmiStatus::OUT_OF_RANGE.linux_errno(), Some(LinuxError::ERANGE));
+/// assert_eq!(ScmiStatus::from(AxError::Busy), ScmiStatus::BUSY);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct ScmiStatus(pub i32);
+
+impl ScmiStatus {
+    /// Success.
+    pub const SUCCESS: Self = Self(0);
+
+    /// Returns `true` if the status is [`SUCCESS`](Self::SUCCESS).
+    pub const fn is_ok(self) -> bool {
+        self.0 == Self::SUCCESS.0
+    }
+
+    /// Returns `true` if the status is not [`SUCCESS`](Self::SUCCESS).
+    pub const fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Converts the status into an [`AxResult`].
+    ///
+    /// Unknown codes become [`Io`](AxError::Io).
+    pub fn into_result(self) -> AxResult {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(self.ax_error())
+        }
+    }
+}
+
+scmi_status! {
+    NOT_SUPPORTED = -1 => NotSupported, EOPNOTSUPP;
+    INVALID_PARAMETERS = -2 => InvalidInput, EINVAL;
+    DENIED = -3 => PermissionDenied, EACCES;
+    NOT_FOUND = -4 => NotFound, ENOENT;
+    OUT_OF_RANGE = -5 => OutOfRange, ERANGE;
+    BUSY = -6 => Busy, EBUSY;
+    COMMS_ERROR = -7 => CommunicationError, ECOMM;
+    GENERIC_ERROR = -8 => Io, EIO;
+    HARDWARE_ERROR = -9 => RemoteIo, EREMOTEIO;
+    PROTOCOL_ERROR = -10 => ProtocolError, EPROTO;
+    IN_USE = -11 => ResourceBusy, EBUSY;
+}
+
+impl From<AxError> for ScmiStatus {
+    fn from(e: AxError) -> Self {
+        use AxError::*;
+        match e {
+            Busy => Self::BUSY,
+            CommunicationError => Self::COMMS_ERROR,
+            InvalidData | ProtocolError => Self::PROTOCOL_ERROR,
+            InvalidInput => Self::INVALID_PARAMETERS,
+            NotFound => Self::NOT_FOUND,
+            OutOfRange => Self::OUT_OF_RANGE,
+            PermissionDenied => Self::DENIED,
+            RemoteIo => Self::HARDWARE_ERROR,
+            ResourceBusy => Self::IN_USE,
+            Unsupported | NotSupported => Self::NOT_SUPPORTED,
+            _ => Self::GENERIC_ERROR,
+        }
+    }
+}
+
+impl From<AxResult> for ScmiStatus {
+    fn from(res: AxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::SUCCESS)
+    }
+}
+
+impl fmt::Debug for ScmiStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "ScmiStatus({})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scmi_status() {
+        assert_eq!(ScmiStatus::SUCCESS.into_result(), Ok(()));
+        assert_eq!(ScmiStatus::SUCCESS.linux_errno(), None);
+        assert_eq!(
+            ScmiStatus::COMMS_ERROR.into_result(),
+            Err(AxError::CommunicationError)
+        );
+        assert_eq!(ScmiStatus(-100).linux_errno(), Some(LinuxError::EIO));
+        assert_eq!(ScmiStatus(-100).name(), None);
+
+        for e in [
+            AxError::NotFound,
+            AxError::NotSupported,
+            AxError::OutOfRange,
+            AxError::ResourceBusy,
+        ] {
+            assert_eq!(ScmiStatus::from(e).into_result(), Err(e));
+        }
+
+        for code in -11..0 {
+            let status = ScmiStatus(code);
+            assert!(status.name().is_some());
+            assert_eq!(
+                LinuxError::from(status.into_result().unwrap_err()),
+                status.linux_errno().unwrap()
+            );
+        }
+    }
+}
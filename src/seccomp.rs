@@ -0,0 +1,84 @@
+//! Error codes in seccomp filter return actions.
+//!
+//! A seccomp filter failing a syscall returns [`SECCOMP_RET_ERRNO`] with the
+//! error code in the [`SECCOMP_RET_DATA`] bits, built with [`ret_errno`] and
+//! decoded with [`decode_errno`].
+
+use crate::syscall::MAX_ERRNO;
+use crate::LinuxError;
+
+/// Kill the process.
+pub const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+/// Kill the thread.
+pub const SECCOMP_RET_KILL_THREAD: u32 = 0x0000_0000;
+/// Send `SIGSYS`.
+pub const SECCOMP_RET_TRAP: u32 = 0x0003_0000;
+/// Fail the syscall with the error code in the data bits.
+pub const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+/// Notify the user-space supervisor.
+pub const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+/// Pass to the tracer.
+pub const SECCOMP_RET_TRACE: u32 = 0x7ff0_0000;
+/// Allow after logging.
+pub const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+/// Allow.
+pub const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+/// The mask of the action, including the kill-process bit.
+pub const SECCOMP_RET_ACTION_FULL: u32 = 0xffff_0000;
+/// The mask of the action, without the kill-process bit.
+pub const SECCOMP_RET_ACTION: u32 = 0x7fff_0000;
+/// The mask of the data.
+pub const SECCOMP_RET_DATA: u32 = 0x0000_ffff;
+
+/// Builds the return action failing the syscall with `err`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{seccomp::{decode_errno, ret_errno}, LinuxError};
+/// #
+/// let ret = ret_errno(LinuxError::EPERM);
+/// assert_eq!(ret, 0x0005_0001);
+/// assert_eq!(decode_errno(ret), Some(LinuxError::EPERM));
+/// ```
+pub const fn ret_errno(err: LinuxError) -> u32 {
+    SECCOMP_RET_ERRNO | (err.code() as u32 & SECCOMP_RET_DATA)
+}
+
+/// Decodes the error code of a [`SECCOMP_RET_ERRNO`] return action.
+///
+/// Returns `None` for other actions and for a zero error code, with which
+/// the syscall returns `0`. Codes above [`MAX_ERRNO`] are clamped to it, as
+/// Linux does.
+pub fn decode_errno(ret: u32) -> Option<LinuxError> {
+    if ret & SECCOMP_RET_ACTION_FULL != SECCOMP_RET_ERRNO {
+        return None;
+    }
+    let data = (ret & SECCOMP_RET_DATA).min(MAX_ERRNO as u32);
+    LinuxError::new(data as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seccomp_errno() {
+        assert_eq!(ret_errno(LinuxError::ENOSYS), SECCOMP_RET_ERRNO | 38);
+        assert_eq!(
+            decode_errno(SECCOMP_RET_ERRNO | 38),
+            Some(LinuxError::ENOSYS)
+        );
+        assert_eq!(decode_errno(SECCOMP_RET_ERRNO), None);
+        assert_eq!(
+            decode_errno(SECCOMP_RET_ERRNO | 0xffff),
+            LinuxError::new(MAX_ERRNO as i32)
+        );
+        assert_eq!(decode_errno(SECCOMP_RET_ALLOW | 1), None);
+        assert_eq!(
+            decode_errno(SECCOMP_RET_KILL_PROCESS | SECCOMP_RET_ERRNO | 1),
+            None
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Compact sets of [`LinuxError`]s.
+//!
+//! [`ErrorSet`] is a plain value, while [`OneOf`] carries a set in its type,
+//! so the errors a function can fail with are part of its signature.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::syscall::MAX_ERRNO;
+use crate::{AxError, LinuxError};
+
+const WORDS: usize = MAX_ERRNO / 64 + 1;
+
+/// A bitset of [`LinuxError`]s that can be built in const context.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{set::ErrorSet, LinuxError};
+/// #
+/// const RESTARTABLE: ErrorSet = ErrorSet::new(&[LinuxError::EINTR, LinuxError::EAGAIN]);
+///
+/// assert!(RESTARTABLE.contains(LinuxError::EINTR));
+/// assert!(!RESTARTABLE.contains(LinuxError::ENOENT));
+/// assert_eq!(RESTARTABLE.len(), 2);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct ErrorSet {
+    bits: [u64; WORDS],
+}
+
+impl ErrorSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self { bits: [0; WORDS] };
+
+    /// Constructs a set holding the given errors.
+    pub const fn new(errs: &[LinuxError]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < errs.len() {
+            set = set.with(errs[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns a copy of the set with `err` added.
+    pub const fn with(mut self, err: LinuxError) -> Self {
+        let code = err.code() as usize;
+        self.bits[code / 64] |= 1 << (code % 64);
+        self
+    }
+
+    /// Returns a copy of the set with `err` removed.
+    pub const fn without(mut self, err: LinuxError) -> Self {
+        let code = err.code() as usize;
+        self.bits[code / 64] &= !(1 << (code % 64));
+        self
+    }
+
+    /// Returns `true` if the set contains `err`.
+    pub const fn contains(&self, err: LinuxError) -> bool {
+        self.contains_code(err.code())
+    }
+
+    /// Returns `true` if the set contains the error with the raw `code`.
+    pub const fn contains_code(&self, code: i32) -> bool {
+        if code < 0 || code as usize > MAX_ERRNO {
+            return false;
+        }
+        let code = code as usize;
+        self.bits[code / 64] & (1 << (code % 64)) != 0
+    }
+
+    /// Returns the errors contained in `self` or `other`.
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < WORDS {
+            self.bits[i] |= other.bits[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the errors contained in both `self` and `other`.
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < WORDS {
+            self.bits[i] &= other.bits[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns `true` if every error of `self` is also in `other`.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.bits[i] & !other.bits[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if the set contains no errors.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of errors in the set.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < WORDS {
+            len += self.bits[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    /// Returns an iterator over the errors in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = LinuxError> + '_ {
+        (1..=MAX_ERRNO as i32)
+            .filter(|&code| self.contains_code(code))
+            .filter_map(|code| LinuxError::try_from(code).ok())
+    }
+}
+
+impl Default for ErrorSet {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl FromIterator<LinuxError> for ErrorSet {
+    fn from_iter<I: IntoIterator<Item = LinuxError>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl fmt::Debug for ErrorSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A marker type naming a set of errors, usually defined with
+/// [`errno_set!`](crate::errno_set).
+pub trait Errnos {
+    /// The errors in the set.
+    const SET: ErrorSet;
+}
+
+/// A [`LinuxError`] that is known to belong to the set `S`.
+///
+/// Functions returning `Result<T, OneOf<S>>` document the errors they can
+/// fail with in their signature. Values are created with
+/// [`one_of!`](crate::one_of), which rejects errors outside of `S` at compile
+/// time, or checked at run time with [`OneOf::new`]. [`OneOf::widen`] moves
+/// to a larger set and fails to compile unless `S` is a subset of it.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{errno_set, one_of, set::OneOf, LinuxError, LinuxResult};
+/// #
+/// errno_set! {
+///     /// The errors of `copy_from_user`.
+///     pub CopyErrors = [EFAULT];
+///     /// The errors of `sys_pipe`.
+///     pub PipeErrors = [EFAULT, EMFILE, ENFILE];
+/// }
+///
+/// fn copy_from_user(addr: usize) -> Result<u32, OneOf<CopyErrors>> {
+///     if addr == 0 {
+///         return Err(one_of!(CopyErrors, EFAULT));
+///     }
+///     Ok(0)
+/// }
+///
+/// fn sys_pipe(fds: usize) -> Result<(), OneOf<PipeErrors>> {
+///     copy_from_user(fds).map_err(OneOf::widen)?;
+///     Err(one_of!(PipeErrors, EMFILE))
+/// }
+///
+/// fn sys_pipe2(fds: usize) -> LinuxResult {
+///     Ok(sys_pipe(fds)?)
+/// }
+///
+/// assert_eq!(sys_pipe2(0), Err(LinuxError::EFAULT));
+/// assert_eq!(sys_pipe2(8), Err(LinuxError::EMFILE));
+/// assert!(OneOf::<PipeErrors>::new(LinuxError::ENOMEM).is_none());
+/// ```
+///
+/// Errors outside of the set do not compile:
+///
+/// ```compile_fail
+/// # use axerrno::{errno_set, one_of};
+/// #
+/// errno_set! {
+///     pub CopyErrors = [EFAULT];
+/// }
+///
+/// let _ = one_of!(CopyErrors, ENOMEM);
+/// ```
+///
+/// Neither do conversions to a set missing some of the errors:
+///
+/// ```compile_fail
+/// # use axerrno::{errno_set, set::OneOf};
+/// #
+/// errno_set! {
+///     pub CopyErrors = [EFAULT];
+///     pub MapErrors = [ENOMEM];
+/// }
+///
+/// fn narrow(err: OneOf<CopyErrors>) -> OneOf<MapErrors> {
+///     err.widen()
+/// }
+/// # narrow(OneOf::new(axerrno::LinuxError::EFAULT).unwrap());
+/// ```
+pub struct OneOf<S: Errnos> {
+    err: LinuxError,
+    _set: PhantomData<fn() -> S>,
+}
+
+impl<S: Errnos> OneOf<S> {
+    /// Wraps `err`, or returns `None` if it is not in `S`.
+    pub const fn new(err: LinuxError) -> Option<Self> {
+        if S::SET.contains(err) {
+            Some(Self {
+                err,
+                _set: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Wraps `err`, panicking if it is not in `S`.
+    ///
+    /// Evaluated in a const context, as by [`one_of!`](crate::one_of), the
+    /// panic is a compile error.
+    #[track_caller]
+    pub const fn new_checked(err: LinuxError) -> Self {
+        match Self::new(err) {
+            Some(err) => err,
+            None => panic!("error is not in the declared set"),
+        }
+    }
+
+    /// Converts an [`AxError`], or returns `None` if it maps to an error that
+    /// is not in `S`.
+    pub fn from_ax(err: AxError) -> Option<Self> {
+        Self::new(err.into())
+    }
+
+    /// Returns the wrapped error.
+    pub const fn get(self) -> LinuxError {
+        self.err
+    }
+
+    /// Converts to a set that contains `S`.
+    ///
+    /// Fails to compile if `T` misses any error of `S`.
+    pub const fn widen<T: Errnos>(self) -> OneOf<T> {
+        const {
+            assert!(
+                S::SET.is_subset(&T::SET),
+                "the target set misses errors of the source set"
+            )
+        };
+        OneOf {
+            err: self.err,
+            _set: PhantomData,
+        }
+    }
+}
+
+impl<S: Errnos> Clone for OneOf<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Errnos> Copy for OneOf<S> {}
+
+impl<S: Errnos> PartialEq for OneOf<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.err == other.err
+    }
+}
+
+impl<S: Errnos> Eq for OneOf<S> {}
+
+impl<S: Errnos> PartialEq<LinuxError> for OneOf<S> {
+    fn eq(&self, other: &LinuxError) -> bool {
+        self.err == *other
+    }
+}
+
+impl<S: Errnos> From<OneOf<S>> for LinuxError {
+    fn from(e: OneOf<S>) -> Self {
+        e.err
+    }
+}
+
+impl<S: Errnos> fmt::Debug for OneOf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.err, f)
+    }
+}
+
+impl<S: Errnos> fmt::Display for OneOf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.err, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_set() {
+        let set = ErrorSet::new(&[LinuxError::EPERM, LinuxError::EHWPOISON]);
+        assert!(set.contains(LinuxError::EPERM));
+        assert!(set.contains(LinuxError::EHWPOISON));
+        assert!(!set.contains_code(0));
+        assert!(!set.contains_code(-1));
+        assert!(!set.contains_code(i32::MAX));
+        assert_eq!(set.len(), 2);
+
+        let other: ErrorSet = [LinuxError::EPERM, LinuxError::EIO].into_iter().collect();
+        assert_eq!(set.intersection(other), ErrorSet::new(&[LinuxError::EPERM]));
+        assert_eq!(set.union(other).len(), 3);
+        assert!(set
+            .without(LinuxError::EPERM)
+            .without(LinuxError::EHWPOISON)
+            .is_empty());
+        assert!(set.iter().eq([LinuxError::EPERM, LinuxError::EHWPOISON]));
+        assert!(set.is_subset(&set.union(other)));
+        assert!(!set.is_subset(&other));
+        assert!(ErrorSet::EMPTY.is_subset(&other));
+
+        let unnamed = LinuxError::new(4000).unwrap();
+        let set = ErrorSet::EMPTY.with(unnamed).with(LinuxError::EPERM);
+        assert!(set.contains(unnamed));
+        assert!(set.contains_code(4000));
+        assert!(!set.contains_code(4096));
+        assert!(set.iter().eq([LinuxError::EPERM, unnamed]));
+        assert!(set.without(unnamed).iter().eq([LinuxError::EPERM]));
+    }
+
+    crate::errno_set! {
+        Small = [EINVAL];
+        Large = [EINVAL, EFAULT, ENOMEM];
+    }
+
+    #[test]
+    fn test_one_of() {
+        let err = crate::one_of!(Small, EINVAL);
+        assert_eq!(err.widen::<Large>(), LinuxError::EINVAL);
+        assert_eq!(OneOf::<Small>::new(LinuxError::EFAULT), None);
+        assert_eq!(
+            OneOf::<Large>::from_ax(AxError::NoMemory).map(OneOf::get),
+            Some(LinuxError::ENOMEM)
+        );
+        assert_eq!(OneOf::<Small>::from_ax(AxError::NoMemory), None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Restarting syscalls interrupted by signals.
+//!
+//! A syscall interrupted by a signal returns one of the kernel-internal
+//! `ERESTART*` codes. Before returning to user space, the signal delivery code
+//! decides with [`should_restart`] or [`restart_without_handler`] whether the
+//! syscall is restarted or fails with [`EINTR`](LinuxError::EINTR), following
+//! Linux's rules.
+
+use crate::LinuxError;
+
+/// What to do with a syscall that returned an error when a signal is
+/// delivered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RestartAction {
+    /// Return the error to user space unchanged; it is not a restart code.
+    Return,
+    /// Fail the syscall with [`EINTR`](LinuxError::EINTR).
+    Interrupt,
+    /// Restart the syscall with the same arguments, by rewinding the program
+    /// counter to the syscall instruction.
+    Restart,
+    /// Restart the syscall through `restart_syscall(2)`, which resumes from
+    /// the state saved in the restart block.
+    RestartBlock,
+}
+
+impl RestartAction {
+    /// Returns the error code to report to user space for `err`, or `None`
+    /// if the syscall is restarted.
+    pub const fn user_errno(self, err: LinuxError) -> Option<LinuxError> {
+        match self {
+            RestartAction::Return => Some(err),
+            RestartAction::Interrupt => Some(LinuxError::EINTR),
+            RestartAction::Restart | RestartAction::RestartBlock => None,
+        }
+    }
+}
+
+impl LinuxError {
+    /// Returns `true` if the error is one of the `ERESTART*` codes, which are
+    /// resolved by the signal delivery code before returning to user space.
+    pub const fn is_restart(self) -> bool {
+        matches!(
+            self,
+            Self::ERESTARTSYS
+                | Self::ERESTARTNOINTR
+                | Self::ERESTARTNOHAND
+                | Self::ERESTART_RESTARTBLOCK
+        )
+    }
+}
+
+/// Decides whether a syscall that returned `err` is restarted when a signal
+/// handler is about to run. `sa_restart` tells whether the handler was
+/// installed with `SA_RESTART`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{signal::{should_restart, RestartAction}, LinuxError};
+/// #
+/// assert_eq!(should_restart(LinuxError::ERESTARTSYS, true), RestartAction::Restart);
+/// assert_eq!(should_restart(LinuxError::ERESTARTSYS, false), RestartAction::Interrupt);
+/// assert_eq!(should_restart(LinuxError::ERESTARTNOHAND, true), RestartAction::Interrupt);
+/// assert_eq!(should_restart(LinuxError::EAGAIN, true), RestartAction::Return);
+/// ```
+pub const fn should_restart(err: LinuxError, sa_restart: bool) -> RestartAction {
+    match err {
+        LinuxError::ERESTARTNOHAND | LinuxError::ERESTART_RESTARTBLOCK => RestartAction::Interrupt,
+        LinuxError::ERESTARTSYS if sa_restart => RestartAction::Restart,
+        LinuxError::ERESTARTSYS => RestartAction::Interrupt,
+        LinuxError::ERESTARTNOINTR => RestartAction::Restart,
+        _ => RestartAction::Return,
+    }
+}
+
+/// Decides whether a syscall that returned `err` is restarted when no signal
+/// handler runs, e.g. the signal is ignored or stops the task.
+pub const fn restart_without_handler(err: LinuxError) -> RestartAction {
+    match err {
+        LinuxError::ERESTARTNOHAND | LinuxError::ERESTARTSYS | LinuxError::ERESTARTNOINTR => {
+            RestartAction::Restart
+        }
+        LinuxError::ERESTART_RESTARTBLOCK => RestartAction::RestartBlock,
+        _ => RestartAction::Return,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart() {
+        use LinuxError as E;
+        assert_eq!(
+            should_restart(E::ERESTARTNOINTR, false),
+            RestartAction::Restart
+        );
+        assert_eq!(
+            should_restart(E::ERESTART_RESTARTBLOCK, true),
+            RestartAction::Interrupt
+        );
+        assert_eq!(
+            restart_without_handler(E::ERESTARTSYS),
+            RestartAction::Restart
+        );
+        assert_eq!(
+            restart_without_handler(E::ERESTART_RESTARTBLOCK),
+            RestartAction::RestartBlock
+        );
+        assert_eq!(restart_without_handler(E::EINTR), RestartAction::Return);
+
+        assert_eq!(
+            should_restart(E::ERESTARTSYS, false).user_errno(E::ERESTARTSYS),
+            Some(E::EINTR)
+        );
+        assert_eq!(RestartAction::Return.user_errno(E::EIO), Some(E::EIO));
+        assert_eq!(RestartAction::Restart.user_errno(E::ERESTARTSYS), None);
+        assert!(E::ERESTARTSYS.is_kernel_internal());
+        assert!(E::ERESTART_RESTARTBLOCK.is_restart());
+        assert!(!E::ENOIOCTLCMD.is_restart());
+        assert!(!E::EHWPOISON.is_kernel_internal());
+    }
+}
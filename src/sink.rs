@@ -0,0 +1,60 @@
+//! Tracepoint for every error constructed by the error macros.
+//!
+//! A tracer registers an [`ErrorSink`] with [`set_sink`] to record an
+//! [`ErrorEvent`] for each error raised by [`ax_err!`] and its variants,
+//! including the ones whose warning is suppressed.
+//!
+//! The sink is called on the error path, possibly in interrupt context, so it
+//! should only store the event, e.g. in a per-CPU ring buffer.
+//!
+//! [`ax_err!`]: crate::ax_err
+
+use core::panic::Location;
+use core::time::Duration;
+
+use crate::global::GlobalRef;
+use crate::{AxError, AxResult};
+
+/// An error constructed by the error macros.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorEvent {
+    /// The error.
+    pub err: AxError,
+    /// The source location the error was constructed at.
+    pub location: &'static Location<'static>,
+    /// The time the error was constructed, if a clock is installed with
+    /// [`set_clock`](crate::clock::set_clock).
+    pub timestamp: Option<Duration>,
+}
+
+/// Receives the [`ErrorEvent`]s.
+pub trait ErrorSink: Sync {
+    /// Records an event.
+    fn record(&self, event: &ErrorEvent);
+}
+
+static SINK: GlobalRef<dyn ErrorSink> = GlobalRef::new();
+
+/// Registers the error sink.
+///
+/// Returns [`AxError::AlreadyExists`] if a sink is already registered.
+pub fn set_sink(sink: &'static dyn ErrorSink) -> AxResult {
+    SINK.set(sink)
+}
+
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn __emit(err: AxError, location: &'static Location<'static>) -> AxError {
+    crate::hook::call(err, location);
+    #[cfg(feature = "stats")]
+    crate::stats::record(err, location);
+    if let Some(sink) = SINK.get() {
+        sink.record(&ErrorEvent {
+            err,
+            location,
+            timestamp: crate::clock::now(),
+        });
+    }
+    err
+}
@@ -0,0 +1,146 @@
+//! Compile-time identifiers of error sites.
+//!
+//! Each call site of [`ax_err!`] has a [`site_id`] derived from its source
+//! location at compile time. With the `compact-log` feature, the error macros
+//! log only the site ID and the error number instead of the module path and
+//! message, and place a [`SiteRecord`] describing the site in the
+//! [`SITE_SECTION`] link section.
+//!
+//! That section is the decode table: extract it from the kernel image, e.g.
+//! with `objcopy -O binary --only-section=axerrno_sites`, and look IDs up with
+//! [`decode_sites`]. The linker script should `KEEP` the section and may place
+//! it outside of the loaded image.
+//!
+//! [`raise`](crate::raise) logs the ID of its caller's location, known only
+//! at run time, so there is no record for it; look it up by computing
+//! [`site_id`] for the candidate locations instead.
+//!
+//! [`ax_err!`]: crate::ax_err
+
+/// The name of the link section holding the [`SiteRecord`]s.
+pub const SITE_SECTION: &str = "axerrno_sites";
+
+/// Returns the 64-bit hash of the site at `file:line:column` (FNV-1a).
+pub const fn site_hash(file: &str, line: u32, column: u32) -> u64 {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let bytes = file.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash = (hash ^ line as u64).wrapping_mul(PRIME);
+    (hash ^ column as u64).wrapping_mul(PRIME)
+}
+
+/// Returns the ID of the site at `file:line:column`.
+pub const fn site_id(file: &str, line: u32, column: u32) -> u32 {
+    let hash = site_hash(file, line, column);
+    (hash ^ (hash >> 32)) as u32
+}
+
+/// The description of a site, as stored in the [`SITE_SECTION`].
+///
+/// Records consist of the ID, line, column and file name length as
+/// native-endian `u32`s, followed by the file name zero-padded to `N` bytes, a
+/// multiple of 4.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SiteRecord<const N: usize> {
+    id: u32,
+    line: u32,
+    column: u32,
+    file_len: u32,
+    file: [u8; N],
+}
+
+impl<const N: usize> SiteRecord<N> {
+    /// Creates the record of the site at `file:line:column`.
+    ///
+    /// `N` must be the length of `file` rounded up to a multiple of 4.
+    pub const fn new(file: &str, line: u32, column: u32) -> Self {
+        assert!(N == file.len().next_multiple_of(4));
+        let mut bytes = [0; N];
+        let mut i = 0;
+        while i < file.len() {
+            bytes[i] = file.as_bytes()[i];
+            i += 1;
+        }
+        Self {
+            id: site_id(file, line, column),
+            line,
+            column,
+            file_len: file.len() as u32,
+            file: bytes,
+        }
+    }
+}
+
+/// A site decoded by [`decode_sites`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SiteInfo<'a> {
+    /// The [`site_id`] of the site.
+    pub id: u32,
+    /// The source file name.
+    pub file: &'a str,
+    /// The line number.
+    pub line: u32,
+    /// The column number.
+    pub column: u32,
+}
+
+/// Decodes the contents of the [`SITE_SECTION`].
+///
+/// Stops at the first malformed record.
+pub fn decode_sites(mut section: &[u8]) -> impl Iterator<Item = SiteInfo<'_>> {
+    core::iter::from_fn(move || {
+        let u32_at = |i: usize| Some(u32::from_ne_bytes(section.get(i..i + 4)?.try_into().ok()?));
+        // The length is untrusted and may overflow on 32-bit targets.
+        let end = (u32_at(12)? as usize).checked_add(16)?;
+        let file = section.get(16..end)?;
+        let info = SiteInfo {
+            id: u32_at(0)?,
+            file: core::str::from_utf8(file).ok()?,
+            line: u32_at(4)?,
+            column: u32_at(8)?,
+        };
+        let size = end.checked_next_multiple_of(4)?;
+        section = section.get(size..).unwrap_or_default();
+        Some(info)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_sites() {
+        #[repr(C)]
+        struct Section(SiteRecord<12>, SiteRecord<4>);
+        static SECTION: Section = Section(
+            SiteRecord::new("src/fs.rs", 10, 5),
+            SiteRecord::new("a.rs", 1, 2),
+        );
+        // SAFETY: the records are integers and bytes without padding.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                &SECTION as *const Section as *const u8,
+                core::mem::size_of::<Section>(),
+            )
+        };
+
+        let mut sites = decode_sites(bytes);
+        let first = sites.next().unwrap();
+        assert_eq!(first.id, site_id("src/fs.rs", 10, 5));
+        assert_eq!((first.file, first.line, first.column), ("src/fs.rs", 10, 5));
+        assert_eq!(sites.next().unwrap().file, "a.rs");
+        assert_eq!(sites.next(), None);
+        assert_ne!(site_id("a.rs", 1, 2), site_id("a.rs", 2, 1));
+
+        let mut truncated = [0u8; 20];
+        truncated[12..16].copy_from_slice(&u32::MAX.to_ne_bytes());
+        assert_eq!(decode_sites(&truncated).next(), None);
+    }
+}
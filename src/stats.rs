@@ -0,0 +1,117 @@
+//! Per-error counters of the errors raised by the error macros.
+//!
+//! Every error raised by [`ax_err!`] and its variants bumps the counter of
+//! its [`AxError`] and records the source location it was raised at.
+//! [`render_stats`] dumps the counters as text, e.g. to back a
+//! `/proc/axerrno` file or a debug shell command. The codes returned by the
+//! syscalls wrapped in [`syscall_result!`] are counted separately, see
+//! [`returned`].
+//!
+//! [`ax_err!`]: crate::ax_err
+//! [`syscall_result!`]: crate::syscall_result
+
+use core::fmt;
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
+
+use crate::{AxError, LinuxError};
+
+const N: usize = core::mem::variant_count::<AxError>();
+
+struct Counter {
+    count: AtomicU64,
+    last: AtomicPtr<Location<'static>>,
+}
+
+static COUNTERS: [Counter; N] = [const {
+    Counter {
+        count: AtomicU64::new(0),
+        last: AtomicPtr::new(core::ptr::null_mut()),
+    }
+}; N];
+
+fn counter(err: AxError) -> &'static Counter {
+    &COUNTERS[err.code() as usize - 1]
+}
+
+pub(crate) fn record(err: AxError, location: &'static Location<'static>) {
+    let counter = counter(err);
+    counter.count.fetch_add(1, Ordering::Relaxed);
+    counter.last.store(
+        location as *const Location<'static> as *mut _,
+        Ordering::Relaxed,
+    );
+}
+
+const RETURNED_LEN: usize = LinuxError::MAX_CODE as usize;
+
+static RETURNED: [AtomicU64; RETURNED_LEN] = [const { AtomicU64::new(0) }; RETURNED_LEN];
+
+pub(crate) fn record_returned(err: LinuxError) {
+    if let Some(count) = RETURNED.get(err.code() as usize - 1) {
+        count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Returns the number of times `err` has been returned by a syscall wrapped
+/// in [`syscall_result!`](crate::syscall_result).
+///
+/// Only named codes are counted.
+pub fn returned(err: LinuxError) -> u64 {
+    RETURNED
+        .get(err.code() as usize - 1)
+        .map_or(0, |count| count.load(Ordering::Relaxed))
+}
+
+/// Returns the number of times `err` has been raised.
+pub fn count(err: AxError) -> u64 {
+    counter(err).count.load(Ordering::Relaxed)
+}
+
+/// Returns the source location `err` was last raised at, or `None` if it has
+/// never been raised.
+pub fn last_seen(err: AxError) -> Option<&'static Location<'static>> {
+    let last = counter(err).last.load(Ordering::Relaxed);
+    // SAFETY: only `&'static Location` pointers are stored in `last`.
+    unsafe { last.as_ref() }
+}
+
+/// Resets all counters.
+pub fn reset() {
+    for counter in &COUNTERS {
+        counter.count.store(0, Ordering::Relaxed);
+        counter.last.store(core::ptr::null_mut(), Ordering::Relaxed);
+    }
+    for count in &RETURNED {
+        count.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Writes the counters of the errors raised so far, one per line, in the
+/// order of their codes.
+///
+/// Each line has the name of the error, the number of times it was raised
+/// and the location it was last raised at, separated by a space:
+///
+/// ```text
+/// NotFound 3 src/fs/lookup.rs:42:13
+/// ```
+///
+/// Errors that were never raised are omitted. The counters are read one at
+/// a time, so a line may not reflect an error raised concurrently.
+pub fn render_stats(w: &mut impl fmt::Write) -> fmt::Result {
+    for code in 1..=N as i32 {
+        let Ok(err) = AxError::try_from(code) else {
+            continue;
+        };
+        let count = count(err);
+        if count == 0 {
+            continue;
+        }
+        match last_seen(err) {
+            Some(loc) => writeln!(w, "{err:?} {count} {loc}")?,
+            None => writeln!(w, "{err:?} {count} -")?,
+        }
+    }
+    Ok(())
+}
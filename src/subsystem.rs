@@ -0,0 +1,189 @@
+//! Identifiers of the kernel subsystems errors are attributed to.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+/// A kernel subsystem an error is attributed to.
+///
+/// The well-known subsystems are provided as associated constants; other
+/// values are free for out-of-tree components.
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Subsystem(pub u16);
+
+impl Subsystem {
+    /// No particular subsystem.
+    pub const UNKNOWN: Self = Self(0);
+    /// Core kernel: scheduling, syscalls and signals.
+    pub const KERNEL: Self = Self(1);
+    /// File systems and the VFS.
+    pub const FS: Self = Self(2);
+    /// Network stack.
+    pub const NET: Self = Self(3);
+    /// Memory management.
+    pub const MM: Self = Self(4);
+    /// Device drivers.
+    pub const DRIVER: Self = Self(5);
+    /// Inter-process communication.
+    pub const IPC: Self = Self(6);
+
+    /// Returns the name of a well-known subsystem.
+    pub const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::UNKNOWN => "unknown",
+            Self::KERNEL => "kernel",
+            Self::FS => "fs",
+            Self::NET => "net",
+            Self::MM => "mm",
+            Self::DRIVER => "driver",
+            Self::IPC => "ipc",
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Debug for Subsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "Subsystem({name})"),
+            None => write!(f, "Subsystem({})", self.0),
+        }
+    }
+}
+
+impl fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "subsystem {}", self.0),
+        }
+    }
+}
+
+/// A compact error code tagged with the [`Subsystem`] that raised it.
+///
+/// The upper 16 bits hold the subsystem, the lower 16 bits the error: an
+/// [`AxError`] code, or a [`LinuxError`] code with bit 15 set. Both kinds
+/// round-trip losslessly, and are reduced to a plain [`LinuxError`] at the
+/// user boundary via [`errno`](Self::errno).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::subsystem::{Subsystem, TaggedError};
+/// # use axerrno::{AxError, LinuxError};
+/// #
+/// let err = TaggedError::new(Subsystem::FS, AxError::NotFound);
+/// assert_eq!(err.subsystem(), Subsystem::FS);
+/// assert_eq!(err.ax_error(), Some(AxError::NotFound));
+/// assert_eq!(TaggedError::from_raw(err.raw()), Some(err));
+/// assert_eq!(LinuxError::from(err), LinuxError::ENOENT);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct TaggedError(u32);
+
+impl TaggedError {
+    const LINUX_FLAG: u32 = 1 << 15;
+
+    /// Tags an [`AxError`] with a subsystem.
+    pub const fn new(subsystem: Subsystem, err: AxError) -> Self {
+        Self((subsystem.0 as u32) << 16 | err.code() as u32)
+    }
+
+    /// Tags a [`LinuxError`] with a subsystem.
+    pub const fn from_linux(subsystem: Subsystem, err: LinuxError) -> Self {
+        Self((subsystem.0 as u32) << 16 | Self::LINUX_FLAG | err.code() as u32)
+    }
+
+    /// Reconstructs a tagged error from [`raw`](Self::raw).
+    ///
+    /// Returns `None` if the error code is invalid.
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        let tagged = Self(raw);
+        let code = tagged.code();
+        let valid = if raw & Self::LINUX_FLAG != 0 {
+            LinuxError::try_from(code).is_ok()
+        } else {
+            AxError::try_from(code).is_ok()
+        };
+        valid.then_some(tagged)
+    }
+
+    /// Returns the packed representation.
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the subsystem that raised the error.
+    pub const fn subsystem(self) -> Subsystem {
+        Subsystem((self.0 >> 16) as u16)
+    }
+
+    /// Returns the [`AxError`], if the tagged error holds one.
+    pub fn ax_error(self) -> Option<AxError> {
+        if self.0 & Self::LINUX_FLAG != 0 {
+            return None;
+        }
+        AxError::try_from(self.code()).ok()
+    }
+
+    /// Returns the error reported to user space.
+    pub fn errno(self) -> LinuxError {
+        if self.0 & Self::LINUX_FLAG != 0 {
+            LinuxError::try_from(self.code()).unwrap_or(LinuxError::EIO)
+        } else {
+            AxError::try_from(self.code()).map_or(LinuxError::EIO, LinuxError::from)
+        }
+    }
+
+    const fn code(self) -> i32 {
+        (self.0 & (Self::LINUX_FLAG - 1)) as i32
+    }
+}
+
+impl From<TaggedError> for LinuxError {
+    fn from(err: TaggedError) -> Self {
+        err.errno()
+    }
+}
+
+impl fmt::Debug for TaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ax_error() {
+            Some(err) => write!(f, "TaggedError({}, {:?})", self.subsystem(), err),
+            None => write!(f, "TaggedError({}, {:?})", self.subsystem(), self.errno()),
+        }
+    }
+}
+
+impl fmt::Display for TaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ax_error() {
+            Some(err) => write!(f, "{}: {}", self.subsystem(), err),
+            None => write!(f, "{}: {}", self.subsystem(), self.errno()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagged_error() {
+        let err = TaggedError::from_linux(Subsystem::NET, LinuxError::EXDEV);
+        assert_eq!(err.subsystem(), Subsystem::NET);
+        assert_eq!(err.ax_error(), None);
+        assert_eq!(err.errno(), LinuxError::EXDEV);
+        assert_eq!(TaggedError::from_raw(err.raw()), Some(err));
+
+        let err = TaggedError::new(Subsystem(0xbeef), AxError::InvalidData);
+        assert_eq!(err.subsystem(), Subsystem(0xbeef));
+        assert_eq!(err.errno(), LinuxError::EINVAL);
+        assert_eq!(TaggedError::from_raw(0x0001_0000), None);
+        assert!(TaggedError::from_raw(0x0001_8000 | 4095).is_some());
+        assert_eq!(TaggedError::from_raw(0x0001_8000 | 4096), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Syscall return values following the negative-errno convention.
+
+use core::fmt;
+
+use crate::{AxError, AxResult, LinuxError, LinuxResult};
+
+/// The largest error code that can be encoded in a syscall return value.
+///
+/// Return values in `-MAX_ERRNO..=-1` are errors, all others are successful
+/// results.
+pub const MAX_ERRNO: usize = 4095;
+
+/// A raw syscall return value: either a non-negative result or a negated
+/// error code.
+///
+/// It has the same layout as `isize`, so it can be handed to the syscall
+/// trampoline directly.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{syscall::SyscallResult, LinuxError, LinuxResult};
+/// #
+/// let ret = SyscallResult::from(LinuxResult::<usize>::Err(LinuxError::EBADF));
+/// assert_eq!(ret.raw(), -9);
+/// assert!(ret.is_err());
+/// assert_eq!(ret.errno(), Some(LinuxError::EBADF));
+///
+/// let ret = SyscallResult::ok(42).map(|n| n * 2);
+/// assert_eq!(ret.value(), Some(84));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SyscallResult(isize);
+
+impl SyscallResult {
+    /// Constructs a successful return value.
+    ///
+    /// Values whose two's complement falls in the error range (e.g. addresses
+    /// in the last page of the address space) are indistinguishable from
+    /// errors, as on Linux.
+    pub const fn ok(value: usize) -> Self {
+        Self(value as isize)
+    }
+
+    /// Constructs a failed return value.
+    pub const fn err(err: LinuxError) -> Self {
+        Self(-(err.code() as isize))
+    }
+
+    /// Wraps a raw return value.
+    pub const fn from_raw(raw: isize) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw return value.
+    pub const fn raw(self) -> isize {
+        self.0
+    }
+
+    /// Returns `true` if the return value is an error.
+    pub const fn is_err(self) -> bool {
+        self.0 as usize > usize::MAX - MAX_ERRNO
+    }
+
+    /// Returns `true` if the return value is a successful result.
+    pub const fn is_ok(self) -> bool {
+        !self.is_err()
+    }
+
+    /// Returns the raw error code, or `None` on success.
+    pub const fn raw_errno(self) -> Option<i32> {
+        if self.is_err() {
+            Some(-self.0 as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the error code, or `None` on success.
+    pub fn errno(self) -> Option<LinuxError> {
+        LinuxError::try_from(self.raw_errno()?).ok()
+    }
+
+    /// Returns the successful result, or `None` on error.
+    pub const fn value(self) -> Option<usize> {
+        if self.is_err() {
+            None
+        } else {
+            Some(self.0 as usize)
+        }
+    }
+
+    /// Converts to a [`LinuxResult`].
+    pub fn into_result(self) -> LinuxResult<usize> {
+        match self.raw_errno() {
+            None => Ok(self.0 as usize),
+            Some(code) => Err(LinuxError::try_from(code).unwrap_or(LinuxError::EIO)),
+        }
+    }
+
+    /// Maps a successful result with `f`, leaving errors untouched.
+    pub fn map(self, f: impl FnOnce(usize) -> usize) -> Self {
+        match self.value() {
+            Some(value) => Self::ok(f(value)),
+            None => self,
+        }
+    }
+
+    /// Calls `f` with the successful result, leaving errors untouched.
+    pub fn and_then(self, f: impl FnOnce(usize) -> LinuxResult<usize>) -> Self {
+        match self.value() {
+            Some(value) => f(value).into(),
+            None => self,
+        }
+    }
+
+    /// Maps a known error code with `f`, leaving other values untouched.
+    pub fn map_err(self, f: impl FnOnce(LinuxError) -> LinuxError) -> Self {
+        match self.errno() {
+            Some(err) => Self::err(f(err)),
+            None => self,
+        }
+    }
+}
+
+/// Replaces a kernel-internal error code that must not reach user space with
+/// the code user space expects.
+///
+/// `ERESTART*` codes are kept, as the signal delivery code resolves them
+/// with [`should_restart`](crate::signal::should_restart).
+/// [`ENOIOCTLCMD`](LinuxError::ENOIOCTLCMD) becomes
+/// [`ENOTTY`](LinuxError::ENOTTY), [`ENOTSUPP`](LinuxError::ENOTSUPP) becomes
+/// [`EOPNOTSUPP`](LinuxError::EOPNOTSUPP),
+/// [`EPROBE_DEFER`](LinuxError::EPROBE_DEFER) becomes
+/// [`EAGAIN`](LinuxError::EAGAIN) as for Linux's sysfs `bind`, and the other
+/// internal codes become [`EIO`](LinuxError::EIO).
+pub const fn user_visible(err: LinuxError) -> LinuxError {
+    match err {
+        LinuxError::ENOIOCTLCMD => LinuxError::ENOTTY,
+        LinuxError::ENOTSUPP => LinuxError::EOPNOTSUPP,
+        LinuxError::EPROBE_DEFER => LinuxError::EAGAIN,
+        _ if err.is_restart() || !err.is_kernel_internal() => err,
+        _ => LinuxError::EIO,
+    }
+}
+
+impl AxError {
+    /// Replaces a kernel-internal error that must not reach user space, i.e.
+    /// [`ProbeDeferred`](AxError::ProbeDeferred), with
+    /// [`WouldBlock`](AxError::WouldBlock), like [`user_visible`].
+    pub const fn user_visible(self) -> Self {
+        match self {
+            AxError::ProbeDeferred => AxError::WouldBlock,
+            err => err,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __epilogue<E: Into<LinuxError>>(
+    name: &str,
+    res: Result<usize, E>,
+    log: bool,
+) -> SyscallResult {
+    match res {
+        Ok(value) => SyscallResult::ok(value),
+        Err(err) => epilogue_err(name, err.into(), log),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn epilogue_err(name: &str, err: LinuxError, log: bool) -> SyscallResult {
+    let user = user_visible(err);
+    #[cfg(not(feature = "log"))]
+    let _ = (name, log);
+    #[cfg(feature = "log")]
+    if log {
+        log::debug!(target: crate::LOG_TARGET, "{name}: {err:?}");
+    }
+    #[cfg(feature = "stats")]
+    crate::stats::record_returned(user);
+    SyscallResult::err(user)
+}
+
+impl From<LinuxResult<usize>> for SyscallResult {
+    fn from(res: LinuxResult<usize>) -> Self {
+        match res {
+            Ok(value) => Self::ok(value),
+            Err(err) => Self::err(err),
+        }
+    }
+}
+
+impl From<AxResult<usize>> for SyscallResult {
+    fn from(res: AxResult<usize>) -> Self {
+        res.map_err(LinuxError::from).into()
+    }
+}
+
+impl From<SyscallResult> for isize {
+    fn from(res: SyscallResult) -> Self {
+        res.0
+    }
+}
+
+impl fmt::Debug for SyscallResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.raw_errno() {
+            Some(code) => match LinuxError::try_from(code) {
+                Ok(err) => write!(f, "Err({err:?})"),
+                Err(code) => write!(f, "Err({code})"),
+            },
+            None => write!(f, "Ok({})", self.0 as usize),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epilogue() {
+        let res: AxResult<usize> = Ok(7);
+        assert_eq!(crate::syscall_result!(read, res).raw(), 7);
+        let res: LinuxResult<usize> = Err(LinuxError::ENOIOCTLCMD);
+        assert_eq!(
+            crate::syscall_result!(ioctl, quiet, res).errno(),
+            Some(LinuxError::ENOTTY)
+        );
+        let res: AxResult<usize> = Err(AxError::NotFound);
+        assert_eq!(crate::syscall_result!(openat, res).raw(), -2);
+
+        assert_eq!(
+            user_visible(LinuxError::ERESTARTSYS),
+            LinuxError::ERESTARTSYS
+        );
+        assert_eq!(user_visible(LinuxError::EPROBE_DEFER), LinuxError::EAGAIN);
+        assert_eq!(user_visible(LinuxError::EBADHANDLE), LinuxError::EIO);
+        assert!(AxError::ProbeDeferred.is_probe_deferred());
+        assert_eq!(AxError::ProbeDeferred.user_visible(), AxError::WouldBlock);
+        assert_eq!(AxError::Io.user_visible(), AxError::Io);
+        assert_eq!(user_visible(LinuxError::EPERM), LinuxError::EPERM);
+    }
+
+    #[test]
+    fn test_syscall_result() {
+        let ok = SyscallResult::ok(3);
+        assert!(ok.is_ok());
+        assert_eq!(ok.value(), Some(3));
+        assert_eq!(ok.errno(), None);
+        assert_eq!(ok.into_result(), Ok(3));
+
+        let err = SyscallResult::from(AxResult::<usize>::Err(AxError::NotFound));
+        assert_eq!(err.raw(), -2);
+        assert_eq!(err.value(), None);
+        assert_eq!(err.into_result(), Err(LinuxError::ENOENT));
+        assert_eq!(err.map(|v| v + 1), err);
+        assert_eq!(
+            err.map_err(|_| LinuxError::EACCES).errno(),
+            Some(LinuxError::EACCES)
+        );
+
+        assert!(SyscallResult::from_raw(-(MAX_ERRNO as isize)).is_err());
+        assert!(SyscallResult::from_raw(-(MAX_ERRNO as isize) - 1).is_ok());
+        let unnamed = LinuxError::new(4000).unwrap();
+        assert_eq!(SyscallResult::from_raw(-4000).errno(), Some(unnamed));
+        assert_eq!(SyscallResult::from_raw(-4000).into_result(), Err(unnamed));
+        assert_eq!(
+            ok.and_then(|_| Err(LinuxError::EINVAL)).raw(),
+            -LinuxError::EINVAL.code() as isize
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! BSD `sysexits.h` process exit codes.
+
+use core::fmt;
+
+use crate::AxError;
+
+/// Process exit codes defined by BSD `sysexits.h`.
+///
+/// Exit statuses of programs can be derived from kernel errors with the
+/// [`From<AxError>`](#impl-From<AxError>-for-SysExit) conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{sysexits::SysExit, AxError};
+/// #
+/// assert_eq!(SysExit::from(AxError::NotFound), SysExit::NoInput);
+/// assert_eq!(SysExit::from(AxError::PermissionDenied).code(), 77);
+/// ```
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SysExit {
+    /// Successful termination (`EX_OK`).
+    Ok = 0,
+    /// Command line usage error (`EX_USAGE`).
+    Usage = 64,
+    /// Data format error (`EX_DATAERR`).
+    DataErr = 65,
+    /// Cannot open input (`EX_NOINPUT`).
+    NoInput = 66,
+    /// Addressee unknown (`EX_NOUSER`).
+    NoUser = 67,
+    /// Host name unknown (`EX_NOHOST`).
+    NoHost = 68,
+    /// Service unavailable (`EX_UNAVAILABLE`).
+    Unavailable = 69,
+    /// Internal software error (`EX_SOFTWARE`).
+    Software = 70,
+    /// System error, e.g. can't fork (`EX_OSERR`).
+    OsErr = 71,
+    /// Critical OS file missing (`EX_OSFILE`).
+    OsFile = 72,
+    /// Can't create (user) output file (`EX_CANTCREAT`).
+    CantCreat = 73,
+    /// Input/output error (`EX_IOERR`).
+    IoErr = 74,
+    /// Temporary failure, user is invited to retry (`EX_TEMPFAIL`).
+    TempFail = 75,
+    /// Remote error in protocol (`EX_PROTOCOL`).
+    Protocol = 76,
+    /// Permission denied (`EX_NOPERM`).
+    NoPerm = 77,
+    /// Configuration error (`EX_CONFIG`).
+    Config = 78,
+}
+
+impl SysExit {
+    /// Returns the exit code.
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the name of the constant in `sysexits.h`.
+    pub const fn name(self) -> &'static str {
+        use SysExit::*;
+        match self {
+            Ok => "EX_OK",
+            Usage => "EX_USAGE",
+            DataErr => "EX_DATAERR",
+            NoInput => "EX_NOINPUT",
+            NoUser => "EX_NOUSER",
+            NoHost => "EX_NOHOST",
+            Unavailable => "EX_UNAVAILABLE",
+            Software => "EX_SOFTWARE",
+            OsErr => "EX_OSERR",
+            OsFile => "EX_OSFILE",
+            CantCreat => "EX_CANTCREAT",
+            IoErr => "EX_IOERR",
+            TempFail => "EX_TEMPFAIL",
+            Protocol => "EX_PROTOCOL",
+            NoPerm => "EX_NOPERM",
+            Config => "EX_CONFIG",
+        }
+    }
+}
+
+impl TryFrom<u8> for SysExit {
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => SysExit::Ok,
+            64 => SysExit::Usage,
+            65 => SysExit::DataErr,
+            66 => SysExit::NoInput,
+            67 => SysExit::NoUser,
+            68 => SysExit::NoHost,
+            69 => SysExit::Unavailable,
+            70 => SysExit::Software,
+            71 => SysExit::OsErr,
+            72 => SysExit::OsFile,
+            73 => SysExit::CantCreat,
+            74 => SysExit::IoErr,
+            75 => SysExit::TempFail,
+            76 => SysExit::Protocol,
+            77 => SysExit::NoPerm,
+            78 => SysExit::Config,
+            _ => return Err(code),
+        })
+    }
+}
+
+impl From<AxError> for SysExit {
+    fn from(e: AxError) -> Self {
+        use AxError::*;
+        match e {
+            AddrInUse
+            | ConnectionRefused
+            | ConnectionReset
+            | NotConnected
+            | Unsupported
+            | NotSupported
+            | NoSuchDeviceOrAddress
+            | NoSuchDevice
+            | ProtocolNotSupported
+            | SocketTypeNotSupported
+            | AddressFamilyNotSupported
+            | AddrNotAvailable
+            | NetworkDown
+            | NetworkUnreachable
+            | ConnectionAborted
+            | EndpointShutdown
+            | NoMedium
+            | LinkSevered
+            | RfKill => SysExit::Unavailable,
+            AlreadyExists | DirectoryNotEmpty | ReadOnlyFilesystem | CrossesDevices
+            | FileTooLarge | TooManyLinks | QuotaExceeded => SysExit::CantCreat,
+            BadAddress | BadState | NoChildProcess | OutOfRange | ArgumentOutOfDomain
+            | OwnerDead | NotRecoverable => SysExit::Software,
+            InvalidData | UnexpectedEof | FilesystemCorrupted | InvalidExecutable
+            | BadSharedLibrary | FilesystemLoop | Overflow | IllegalByteSequence
+            | MessageTooLong => SysExit::DataErr,
+            InvalidInput
+            | ArgumentListTooLong
+            | NotATty
+            | NotSeekable
+            | NameTooLong
+            | NotASocket
+            | DestinationAddressRequired
+            | WrongProtocolType
+            | ProtocolOptionUnavailable
+            | AlreadyConnected
+            | NotAStream => SysExit::Usage,
+            Io
+            | StorageFull
+            | WriteZero
+            | BrokenPipe
+            | StaleNetworkFileHandle
+            | CommunicationError
+            | RemoteIo => SysExit::IoErr,
+            IsADirectory | NotADirectory | NotFound | NotABlockDevice | NoMessage
+            | IdentifierRemoved | NoData => SysExit::NoInput,
+            NoMemory | HardwareMemoryError => SysExit::OsErr,
+            PermissionDenied | OperationNotPermitted => SysExit::NoPerm,
+            ResourceBusy | WouldBlock | Interrupted | Timeout | Again | Busy
+            | ExecutableFileBusy | Deadlock | NoLocksAvailable | NoBufferSpace
+            | ConnectionTimedOut | AlreadyInProgress | InProgress | Canceled | ProbeDeferred
+            | NoStreamResources => SysExit::TempFail,
+            NoSuchProcess => SysExit::NoUser,
+            BadFileDescriptor | FileTableOverflow | TooManyOpenFiles => SysExit::OsFile,
+            ProtocolError | BadMessage => SysExit::Protocol,
+            HostDown | HostUnreachable => SysExit::NoHost,
+        }
+    }
+}
+
+impl fmt::Display for SysExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sysexits() {
+        for code in 0..=u8::MAX {
+            if let Ok(exit) = SysExit::try_from(code) {
+                assert_eq!(exit.code(), code);
+            }
+        }
+        assert_eq!(SysExit::from(AxError::InvalidInput), SysExit::Usage);
+        assert_eq!(SysExit::from(AxError::Again).name(), "EX_TEMPFAIL");
+    }
+}
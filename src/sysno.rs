@@ -0,0 +1,210 @@
+//! Tables of the error codes each syscall is documented to return.
+//!
+//! The tables follow the ERRORS sections of the Linux man pages. They are
+//! meant for debug assertions catching syscalls that return error codes user
+//! programs do not expect:
+//!
+//! ```
+//! # use axerrno::{sysno::{self, Sysno}, LinuxError};
+//! #
+//! fn sys_close(fd: i32) -> Result<isize, LinuxError> {
+//!     let res = Err(LinuxError::EBADF);
+//!     if let Err(err) = res {
+//!         debug_assert!(sysno::validate(Sysno::Close, err));
+//!     }
+//!     res
+//! }
+//! # let _ = sys_close(-1);
+//! ```
+
+use crate::set::ErrorSet;
+use crate::LinuxError;
+
+/// Error codes any syscall may return, e.g. when it is not implemented.
+pub const COMMON: ErrorSet = ErrorSet::new(&[LinuxError::ENOSYS]);
+
+macro_rules! syscalls {
+    ($(
+        $variant: ident = ($name: literal, $x86_64: literal, $generic: literal) => [
+            $($err: ident),* $(,)?
+        ],
+    )*) => {
+        /// A syscall with a table of documented error codes.
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum Sysno {
+            $(
+                #[doc = concat!("`", $name, "`")]
+                $variant,
+            )*
+        }
+
+        impl Sysno {
+            /// Returns the syscall name.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// Looks up a syscall by its number on the target architecture.
+            ///
+            /// Always returns `None` on architectures without a table.
+            pub const fn from_raw(sysno: usize) -> Option<Self> {
+                #[cfg(target_arch = "x86_64")]
+                match sysno {
+                    $($x86_64 => Some(Self::$variant),)*
+                    _ => None,
+                }
+                #[cfg(any(
+                    target_arch = "aarch64",
+                    target_arch = "riscv64",
+                    target_arch = "loongarch64"
+                ))]
+                match sysno {
+                    $($generic => Some(Self::$variant),)*
+                    _ => None,
+                }
+                #[cfg(not(any(
+                    target_arch = "x86_64",
+                    target_arch = "aarch64",
+                    target_arch = "riscv64",
+                    target_arch = "loongarch64"
+                )))]
+                {
+                    let _ = sysno;
+                    None
+                }
+            }
+
+            /// Returns the error codes the syscall is documented to return,
+            /// including [`COMMON`].
+            pub const fn allowed(self) -> ErrorSet {
+                match self {
+                    $(Self::$variant => const {
+                        ErrorSet::new(&[$(LinuxError::$err),*]).union(COMMON)
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+syscalls! {
+    Accept4 = ("accept4", 288, 242) => [
+        EAGAIN, EBADF, ECONNABORTED, EFAULT, EINTR, EINVAL, EMFILE, ENFILE, ENOBUFS, ENOMEM,
+        ENOTSOCK, EOPNOTSUPP, EPERM, EPROTO,
+    ],
+    Bind = ("bind", 49, 200) => [
+        EACCES, EADDRINUSE, EADDRNOTAVAIL, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT,
+        ENOMEM, ENOTDIR, ENOTSOCK, EROFS,
+    ],
+    Brk = ("brk", 12, 214) => [ENOMEM],
+    Chdir = ("chdir", 80, 49) => [
+        EACCES, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR,
+    ],
+    ClockGettime = ("clock_gettime", 228, 113) => [EFAULT, EINVAL, EOVERFLOW],
+    Clone = ("clone", 56, 220) => [
+        EAGAIN, EBUSY, EEXIST, EINVAL, ENOMEM, ENOSPC, EOPNOTSUPP, EPERM, EUSERS,
+    ],
+    Close = ("close", 3, 57) => [EBADF, EINTR, EIO, ENOSPC, EDQUOT],
+    Connect = ("connect", 42, 203) => [
+        EACCES, EPERM, EADDRINUSE, EADDRNOTAVAIL, EAFNOSUPPORT, EAGAIN, EALREADY, EBADF,
+        ECONNREFUSED, EFAULT, EINPROGRESS, EINTR, EISCONN, ENETUNREACH, ENOTSOCK, EPROTOTYPE,
+        ETIMEDOUT,
+    ],
+    Dup = ("dup", 32, 23) => [EBADF, EMFILE],
+    Dup3 = ("dup3", 292, 24) => [EBADF, EBUSY, EINTR, EINVAL, EMFILE],
+    Execve = ("execve", 59, 221) => [
+        E2BIG, EACCES, EAGAIN, EFAULT, EINVAL, EIO, EISDIR, ELIBBAD, ELOOP, EMFILE,
+        ENAMETOOLONG, ENFILE, ENOENT, ENOEXEC, ENOMEM, ENOTDIR, EPERM, ETXTBSY,
+    ],
+    Fcntl = ("fcntl", 72, 25) => [
+        EACCES, EAGAIN, EBADF, EBUSY, EDEADLK, EFAULT, EINTR, EINVAL, EMFILE, ENOLCK, ENOTDIR,
+        EPERM,
+    ],
+    Fstat = ("fstat", 5, 80) => [EBADF, EFAULT, EINVAL, ENOMEM, EOVERFLOW],
+    Futex = ("futex", 202, 98) => [
+        EACCES, EAGAIN, EDEADLK, EFAULT, EINTR, EINVAL, ENFILE, ENOMEM, ENOSYS, EPERM, ESRCH,
+        ETIMEDOUT,
+    ],
+    Getcwd = ("getcwd", 79, 17) => [EACCES, EFAULT, EINVAL, ENAMETOOLONG, ENOENT, ERANGE],
+    Getdents64 = ("getdents64", 217, 61) => [EBADF, EFAULT, EINVAL, ENOENT, ENOTDIR],
+    Ioctl = ("ioctl", 16, 29) => [EBADF, EFAULT, EINVAL, ENOTTY],
+    Kill = ("kill", 62, 129) => [EINVAL, EPERM, ESRCH],
+    Listen = ("listen", 50, 201) => [EADDRINUSE, EBADF, ENOTSOCK, EOPNOTSUPP],
+    Lseek = ("lseek", 8, 62) => [EBADF, EINVAL, ENXIO, EOVERFLOW, ESPIPE],
+    Mkdirat = ("mkdirat", 258, 34) => [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EINVAL, ELOOP, EMLINK, ENAMETOOLONG, ENOENT,
+        ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ],
+    Mmap = ("mmap", 9, 222) => [
+        EACCES, EAGAIN, EBADF, EEXIST, EINVAL, ENFILE, ENODEV, ENOMEM, EOVERFLOW, EPERM,
+        ETXTBSY,
+    ],
+    Mprotect = ("mprotect", 10, 226) => [EACCES, EINVAL, ENOMEM],
+    Munmap = ("munmap", 11, 215) => [EINVAL, ENOMEM],
+    Nanosleep = ("nanosleep", 35, 101) => [EFAULT, EINTR, EINVAL],
+    Newfstatat = ("newfstatat", 262, 79) => [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR, EOVERFLOW,
+    ],
+    Openat = ("openat", 257, 56) => [
+        EACCES, EAGAIN, EBADF, EBUSY, EDQUOT, EEXIST, EFAULT, EFBIG, EINTR, EINVAL, EISDIR,
+        ELOOP, EMFILE, ENAMETOOLONG, ENFILE, ENODEV, ENOENT, ENOMEM, ENOSPC, ENOTDIR, ENXIO,
+        EOPNOTSUPP, EOVERFLOW, EPERM, EROFS, ETXTBSY,
+    ],
+    Pipe2 = ("pipe2", 293, 59) => [EFAULT, EINVAL, EMFILE, ENFILE],
+    Read = ("read", 0, 63) => [EAGAIN, EBADF, EFAULT, EINTR, EINVAL, EIO, EISDIR],
+    Recvfrom = ("recvfrom", 45, 207) => [
+        EAGAIN, EBADF, ECONNREFUSED, EFAULT, EINTR, EINVAL, ENOMEM, ENOTCONN, ENOTSOCK,
+    ],
+    Sendto = ("sendto", 44, 206) => [
+        EACCES, EAGAIN, EALREADY, EBADF, ECONNRESET, EDESTADDRREQ, EFAULT, EINTR, EINVAL,
+        EISCONN, EMSGSIZE, ENOBUFS, ENOMEM, ENOTCONN, ENOTSOCK, EOPNOTSUPP, EPIPE,
+    ],
+    Shutdown = ("shutdown", 48, 210) => [EBADF, EINVAL, ENOTCONN, ENOTSOCK],
+    Socket = ("socket", 41, 198) => [
+        EACCES, EAFNOSUPPORT, EINVAL, EMFILE, ENFILE, ENOBUFS, ENOMEM, EPROTONOSUPPORT,
+    ],
+    Unlinkat = ("unlinkat", 263, 35) => [
+        EACCES, EBADF, EBUSY, EFAULT, EINVAL, EIO, EISDIR, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, ENOTEMPTY, EPERM, EROFS,
+    ],
+    Wait4 = ("wait4", 61, 260) => [ECHILD, EFAULT, EINTR, EINVAL],
+    Write = ("write", 1, 64) => [
+        EAGAIN, EBADF, EDESTADDRREQ, EDQUOT, EFAULT, EFBIG, EINTR, EINVAL, EIO, ENOSPC, EPERM,
+        EPIPE,
+    ],
+}
+
+/// Returns `true` if `sysno` is documented to return `err`.
+pub const fn validate(sysno: Sysno, err: LinuxError) -> bool {
+    sysno.allowed().contains(err)
+}
+
+/// Like [`validate`], but takes a raw syscall number.
+///
+/// Returns `true` for syscalls without a table.
+pub const fn validate_raw(sysno: usize, err: LinuxError) -> bool {
+    match Sysno::from_raw(sysno) {
+        Some(sysno) => validate(sysno, err),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(Sysno::Read, LinuxError::EAGAIN));
+        assert!(validate(Sysno::Read, LinuxError::ENOSYS));
+        assert!(!validate(Sysno::Read, LinuxError::ENOENT));
+        assert!(!validate(Sysno::Brk, LinuxError::EINVAL));
+        assert_eq!(Sysno::Openat.name(), "openat");
+        assert!(validate_raw(usize::MAX, LinuxError::EHWPOISON));
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(Sysno::from_raw(257), Some(Sysno::Openat));
+    }
+}
@@ -0,0 +1,172 @@
+//! Conversions between GlobalPlatform TEE result codes and [`AxError`].
+//!
+//! [`TeeResult`] mirrors the `TEE_Result` values returned by OP-TEE and other
+//! GlobalPlatform TEE implementations, as defined in `tee_api_defines.h`.
+
+use core::fmt;
+
+use crate::{AxError, AxResult};
+
+macro_rules! tee_result {
+    ($($name: ident = $value: literal => $err: ident,)*) => {
+        impl TeeResult {
+            $(
+                #[doc = concat!("`", stringify!($name), "`.")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the `TEE_*` name of a known result code.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    Self::TEE_SUCCESS => Some("TEE_SUCCESS"),
+                    $(Self::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+
+            fn ax_error(self) -> AxError {
+                use AxError::*;
+                match self {
+                    $(Self::$name => $err,)*
+                    _ => Io,
+                }
+            }
+        }
+    };
+}
+
+/// A GlobalPlatform TEE result code (`TEE_Result`).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{tee::TeeResult, AxError};
+/// #
+/// assert_eq!(
+///     TeeResult::TEE_ERROR_ITEM_NOT_FOUND.into_result(),
+///     Err(AxError::NotFound)
+/// );
+/// assert_eq!(
+///     TeeResult::from(AxError::NoMemory),
+///     TeeResult::TEE_ERROR_OUT_OF_MEMORY
+/// );
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct TeeResult(pub u32);
+
+impl TeeResult {
+    /// Success.
+    pub const TEE_SUCCESS: Self = Self(0);
+
+    /// Returns `true` if the result is [`TEE_SUCCESS`](Self::TEE_SUCCESS).
+    pub const fn is_ok(self) -> bool {
+        self.0 == Self::TEE_SUCCESS.0
+    }
+
+    /// Returns `true` if the result is not [`TEE_SUCCESS`](Self::TEE_SUCCESS).
+    pub const fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Converts the result into an [`AxResult`].
+    ///
+    /// Unknown codes become [`Io`](AxError::Io).
+    pub fn into_result(self) -> AxResult {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(self.ax_error())
+        }
+    }
+}
+
+tee_result! {
+    TEE_ERROR_CORRUPT_OBJECT = 0xf010_0001 => InvalidData,
+    TEE_ERROR_CORRUPT_OBJECT_2 = 0xf010_0002 => InvalidData,
+    TEE_ERROR_STORAGE_NOT_AVAILABLE = 0xf010_0003 => Io,
+    TEE_ERROR_STORAGE_NOT_AVAILABLE_2 = 0xf010_0004 => Io,
+    TEE_ERROR_CIPHERTEXT_INVALID = 0xf010_0006 => InvalidData,
+    TEE_ERROR_GENERIC = 0xffff_0000 => Io,
+    TEE_ERROR_ACCESS_DENIED = 0xffff_0001 => PermissionDenied,
+    TEE_ERROR_CANCEL = 0xffff_0002 => Interrupted,
+    TEE_ERROR_ACCESS_CONFLICT = 0xffff_0003 => ResourceBusy,
+    TEE_ERROR_EXCESS_DATA = 0xffff_0004 => InvalidInput,
+    TEE_ERROR_BAD_FORMAT = 0xffff_0005 => InvalidData,
+    TEE_ERROR_BAD_PARAMETERS = 0xffff_0006 => InvalidInput,
+    TEE_ERROR_BAD_STATE = 0xffff_0007 => BadState,
+    TEE_ERROR_ITEM_NOT_FOUND = 0xffff_0008 => NotFound,
+    TEE_ERROR_NOT_IMPLEMENTED = 0xffff_0009 => Unsupported,
+    TEE_ERROR_NOT_SUPPORTED = 0xffff_000a => Unsupported,
+    TEE_ERROR_NO_DATA = 0xffff_000b => UnexpectedEof,
+    TEE_ERROR_OUT_OF_MEMORY = 0xffff_000c => NoMemory,
+    TEE_ERROR_BUSY = 0xffff_000d => Busy,
+    TEE_ERROR_COMMUNICATION = 0xffff_000e => Io,
+    TEE_ERROR_SECURITY = 0xffff_000f => PermissionDenied,
+    TEE_ERROR_SHORT_BUFFER = 0xffff_0010 => InvalidInput,
+    TEE_ERROR_EXTERNAL_CANCEL = 0xffff_0011 => Interrupted,
+    TEE_ERROR_OVERFLOW = 0xffff_300f => InvalidInput,
+    TEE_ERROR_TARGET_DEAD = 0xffff_3024 => NotConnected,
+    TEE_ERROR_STORAGE_NO_SPACE = 0xffff_3041 => StorageFull,
+    TEE_ERROR_MAC_INVALID = 0xffff_3071 => InvalidData,
+    TEE_ERROR_SIGNATURE_INVALID = 0xffff_3072 => InvalidData,
+    TEE_ERROR_TIME_NOT_SET = 0xffff_5000 => BadState,
+    TEE_ERROR_TIME_NEEDS_RESET = 0xffff_5001 => BadState,
+}
+
+impl From<AxError> for TeeResult {
+    fn from(e: AxError) -> Self {
+        use AxError::*;
+        match e {
+            BadState => Self::TEE_ERROR_BAD_STATE,
+            Busy | ResourceBusy => Self::TEE_ERROR_BUSY,
+            Interrupted => Self::TEE_ERROR_CANCEL,
+            InvalidData => Self::TEE_ERROR_BAD_FORMAT,
+            InvalidInput => Self::TEE_ERROR_BAD_PARAMETERS,
+            NoMemory => Self::TEE_ERROR_OUT_OF_MEMORY,
+            NotConnected => Self::TEE_ERROR_TARGET_DEAD,
+            NotFound => Self::TEE_ERROR_ITEM_NOT_FOUND,
+            PermissionDenied => Self::TEE_ERROR_ACCESS_DENIED,
+            StorageFull => Self::TEE_ERROR_STORAGE_NO_SPACE,
+            UnexpectedEof => Self::TEE_ERROR_NO_DATA,
+            Unsupported => Self::TEE_ERROR_NOT_SUPPORTED,
+            _ => Self::TEE_ERROR_GENERIC,
+        }
+    }
+}
+
+impl From<AxResult> for TeeResult {
+    fn from(res: AxResult) -> Self {
+        res.map_or_else(Self::from, |()| Self::TEE_SUCCESS)
+    }
+}
+
+impl fmt::Debug for TeeResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "TeeResult({:#010x})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tee_result() {
+        assert_eq!(TeeResult::TEE_SUCCESS.into_result(), Ok(()));
+        assert_eq!(
+            TeeResult::TEE_ERROR_ACCESS_DENIED.into_result(),
+            Err(AxError::PermissionDenied)
+        );
+        assert_eq!(TeeResult(0xffff_1234).into_result(), Err(AxError::Io));
+        assert_eq!(TeeResult::from(Ok(())), TeeResult::TEE_SUCCESS);
+        assert_eq!(TeeResult::TEE_ERROR_BUSY.name(), Some("TEE_ERROR_BUSY"));
+
+        for e in [AxError::NotFound, AxError::Busy, AxError::StorageFull] {
+            assert_eq!(TeeResult::from(e).into_result(), Err(e));
+        }
+    }
+}
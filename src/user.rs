@@ -0,0 +1,135 @@
+//! Error codes that may be returned to user space.
+
+use core::fmt;
+
+use crate::syscall::user_visible;
+use crate::{AxError, Errno, LinuxError};
+
+/// A [`LinuxError`] that is legal to return to user space.
+///
+/// It can only hold codes below the kernel-internal range, so `ERESTART*`,
+/// [`ENOIOCTLCMD`](LinuxError::ENOIOCTLCMD) and the other
+/// [kernel-internal](LinuxError::is_kernel_internal) codes, as well as the
+/// codes of custom [error domains](crate::domain), cannot leak through an
+/// interface taking a `UserError`.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{user::UserError, AxError, LinuxError};
+/// #
+/// assert!(UserError::new(LinuxError::EINVAL).is_some());
+/// assert!(UserError::new(LinuxError::ERESTARTSYS).is_none());
+///
+/// let err = UserError::sanitize(LinuxError::ENOIOCTLCMD);
+/// assert_eq!(err.get(), LinuxError::ENOTTY);
+/// assert_eq!(UserError::from(AxError::NotFound).code(), 2);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UserError(LinuxError);
+
+impl UserError {
+    /// Wraps `err`, or returns `None` if it is kernel-internal.
+    pub const fn new(err: LinuxError) -> Option<Self> {
+        if err.is_kernel_internal() {
+            None
+        } else {
+            Some(Self(err))
+        }
+    }
+
+    /// Wraps the error with the positive code `code`, or returns `None` if it
+    /// is not a legal user-space code.
+    pub const fn from_code(code: i32) -> Option<Self> {
+        match LinuxError::new(code) {
+            Some(err) => Self::new(err),
+            None => None,
+        }
+    }
+
+    /// Converts `err` to the code user space sees for it.
+    ///
+    /// Kernel-internal codes are replaced as by
+    /// [`user_visible`], and the `ERESTART*` codes, which are resolved by the
+    /// signal delivery code, become [`EINTR`](LinuxError::EINTR).
+    pub const fn sanitize(err: LinuxError) -> Self {
+        match user_visible(err) {
+            err if err.is_restart() => Self(LinuxError::EINTR),
+            err => Self(err),
+        }
+    }
+
+    /// Returns the wrapped error.
+    pub const fn get(self) -> LinuxError {
+        self.0
+    }
+
+    /// Returns the positive error code.
+    pub const fn code(self) -> i32 {
+        self.0.code()
+    }
+}
+
+impl From<AxError> for UserError {
+    fn from(e: AxError) -> Self {
+        Self::sanitize(e.into())
+    }
+}
+
+impl TryFrom<LinuxError> for UserError {
+    type Error = LinuxError;
+
+    fn try_from(e: LinuxError) -> Result<Self, Self::Error> {
+        Self::new(e).ok_or(e)
+    }
+}
+
+impl From<UserError> for LinuxError {
+    fn from(e: UserError) -> Self {
+        e.0
+    }
+}
+
+impl From<UserError> for Errno {
+    fn from(e: UserError) -> Self {
+        e.0.into()
+    }
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::CUSTOM_CODE_BASE;
+
+    #[test]
+    fn test_user_error() {
+        assert_eq!(
+            UserError::from_code(13).map(UserError::get),
+            Some(LinuxError::EACCES)
+        );
+        assert_eq!(UserError::from_code(0), None);
+        assert_eq!(UserError::from_code(512), None);
+        assert_eq!(UserError::from_code(CUSTOM_CODE_BASE), None);
+        assert_eq!(
+            UserError::try_from(LinuxError::EPROBE_DEFER),
+            Err(LinuxError::EPROBE_DEFER)
+        );
+        assert_eq!(
+            UserError::sanitize(LinuxError::ERESTART_RESTARTBLOCK).get(),
+            LinuxError::EINTR
+        );
+        assert_eq!(
+            UserError::sanitize(LinuxError::EBADHANDLE).get(),
+            LinuxError::EIO
+        );
+        assert_eq!(UserError::from(AxError::ProbeDeferred).code(), 11);
+        assert_eq!(Errno::from(UserError::from(AxError::Again)), Errno(11));
+    }
+}
@@ -0,0 +1,179 @@
+//! Stable wire encoding of error codes.
+//!
+//! Both error types are encoded as their error code in a 4-byte little-endian
+//! integer, suitable for on-disk journals and cross-VM messages.
+//!
+//! # Stability
+//!
+//! Within one [`ABI_VERSION`]:
+//!
+//! - the code of an existing [`AxError`] or [`LinuxError`] never changes;
+//! - new [`AxError`] variants only take codes above the current maximum;
+//! - codes are never reused for a different error.
+//!
+//! Decoding a code written by a newer version that is unknown to this version
+//! fails and returns the raw code, so readers can still preserve it.
+//!
+//! These guarantees cover the built-in tables only. A custom table loaded
+//! through `AXERRNO_ERRNO_TABLE` may give an existing [`LinuxError`] another
+//! code, so writers and readers must be built with the same table.
+
+use crate::{AxError, LinuxError};
+
+/// The version of the wire encoding defined by this module.
+///
+/// It is bumped whenever an encoded value may change meaning.
+pub const ABI_VERSION: u32 = 1;
+
+/// The size in bytes of an encoded error.
+pub const WIRE_SIZE: usize = 4;
+
+impl AxError {
+    /// Encodes the error in the stable wire format.
+    pub const fn to_wire(self) -> [u8; WIRE_SIZE] {
+        self.code().to_le_bytes()
+    }
+
+    /// Decodes an error from the stable wire format.
+    ///
+    /// Returns the raw code if it is unknown.
+    pub fn from_wire(bytes: [u8; WIRE_SIZE]) -> Result<Self, i32> {
+        Self::try_from(i32::from_le_bytes(bytes))
+    }
+}
+
+impl LinuxError {
+    /// Encodes the error in the stable wire format.
+    pub const fn to_wire(self) -> [u8; WIRE_SIZE] {
+        self.code().to_le_bytes()
+    }
+
+    /// Decodes an error from the stable wire format.
+    ///
+    /// Returns the raw code if it is unknown.
+    pub fn from_wire(bytes: [u8; WIRE_SIZE]) -> Result<Self, i32> {
+        Self::try_from(i32::from_le_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pins the code of every [`AxError`]. Existing entries must never change.
+    #[test]
+    fn test_ax_error_codes_are_stable() {
+        use AxError::*;
+        let pinned = [
+            (AddrInUse, 1),
+            (AlreadyExists, 2),
+            (BadAddress, 3),
+            (BadState, 4),
+            (ConnectionRefused, 5),
+            (ConnectionReset, 6),
+            (DirectoryNotEmpty, 7),
+            (InvalidData, 8),
+            (InvalidInput, 9),
+            (Io, 10),
+            (IsADirectory, 11),
+            (NoMemory, 12),
+            (NotADirectory, 13),
+            (NotConnected, 14),
+            (NotFound, 15),
+            (PermissionDenied, 16),
+            (ResourceBusy, 17),
+            (StorageFull, 18),
+            (UnexpectedEof, 19),
+            (Unsupported, 20),
+            (WouldBlock, 21),
+            (WriteZero, 22),
+            (Interrupted, 23),
+            (Timeout, 24),
+            (Again, 25),
+            (Busy, 26),
+            (FilesystemCorrupted, 27),
+            (ReadOnlyFilesystem, 28),
+            (InvalidExecutable, 29),
+            (NotSupported, 30),
+            (OperationNotPermitted, 31),
+            (NoSuchProcess, 32),
+            (NoSuchDeviceOrAddress, 33),
+            (ArgumentListTooLong, 34),
+            (BadFileDescriptor, 35),
+            (NoChildProcess, 36),
+            (NotABlockDevice, 37),
+            (CrossesDevices, 38),
+            (NoSuchDevice, 39),
+            (FileTableOverflow, 40),
+            (TooManyOpenFiles, 41),
+            (NotATty, 42),
+            (ExecutableFileBusy, 43),
+            (FileTooLarge, 44),
+            (NotSeekable, 45),
+            (TooManyLinks, 46),
+            (BrokenPipe, 47),
+            (OutOfRange, 48),
+            (Deadlock, 49),
+            (NameTooLong, 50),
+            (NoLocksAvailable, 51),
+            (FilesystemLoop, 52),
+            (NoMessage, 53),
+            (IdentifierRemoved, 54),
+            (NoData, 55),
+            (ProtocolError, 56),
+            (BadMessage, 57),
+            (Overflow, 58),
+            (IllegalByteSequence, 59),
+            (NotASocket, 60),
+            (DestinationAddressRequired, 61),
+            (MessageTooLong, 62),
+            (WrongProtocolType, 63),
+            (ProtocolOptionUnavailable, 64),
+            (ProtocolNotSupported, 65),
+            (SocketTypeNotSupported, 66),
+            (AddressFamilyNotSupported, 67),
+            (AddrNotAvailable, 68),
+            (NetworkDown, 69),
+            (NetworkUnreachable, 70),
+            (ConnectionAborted, 71),
+            (NoBufferSpace, 72),
+            (AlreadyConnected, 73),
+            (EndpointShutdown, 74),
+            (ConnectionTimedOut, 75),
+            (HostDown, 76),
+            (HostUnreachable, 77),
+            (AlreadyInProgress, 78),
+            (InProgress, 79),
+            (StaleNetworkFileHandle, 80),
+            (QuotaExceeded, 81),
+            (NoMedium, 82),
+            (Canceled, 83),
+            (HardwareMemoryError, 84),
+            (ProbeDeferred, 85),
+            (NoStreamResources, 86),
+            (NotAStream, 87),
+            (LinkSevered, 88),
+            (ArgumentOutOfDomain, 89),
+            (OwnerDead, 90),
+            (NotRecoverable, 91),
+            (RfKill, 92),
+            (BadSharedLibrary, 93),
+            (CommunicationError, 94),
+            (RemoteIo, 95),
+        ];
+        for (err, code) in pinned {
+            assert_eq!(err.to_wire(), [code, 0, 0, 0]);
+            assert_eq!(AxError::from_wire([code, 0, 0, 0]), Ok(err));
+        }
+    }
+
+    #[test]
+    fn test_linux_error_wire() {
+        assert_eq!(LinuxError::EHWPOISON.to_wire(), [133, 0, 0, 0]);
+        assert_eq!(
+            LinuxError::from_wire(LinuxError::EPERM.to_wire()),
+            Ok(LinuxError::EPERM)
+        );
+        assert_eq!(LinuxError::from_wire([0xff; 4]), Err(-1));
+    }
+}
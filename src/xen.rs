@@ -0,0 +1,182 @@
+//! Xen hypercall return values.
+//!
+//! Hypercalls return a non-negative value on success or a negated `XEN_E*`
+//! code, as defined in Xen's `public/errno.h`. These codes share their values
+//! with Linux, but Xen only defines a subset of them, and `XEN_ERESTART` is a
+//! hypervisor-internal continuation code that guests should never observe.
+//! [`XenErrno::to_linux`] only accepts codes Xen actually defines.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError, LinuxResult};
+
+macro_rules! xen_errno {
+    ($($name: ident = $value: literal,)*) => {
+        impl XenErrno {
+            $(
+                #[doc = concat!("`XEN_", stringify!($name), "`.")]
+                pub const $name: Self = Self($value);
+            )*
+
+            /// Returns the `XEN_E*` name of the code, or `None` if Xen does
+            /// not define it.
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$name => Some(concat!("XEN_", stringify!($name))),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// An error code returned by a Xen hypercall, as a positive number.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::xen::{decode_hypercall, XenErrno};
+/// # use axerrno::LinuxError;
+/// #
+/// assert_eq!(decode_hypercall(3), Ok(3));
+/// assert_eq!(decode_hypercall(-12), Err(XenErrno::ENOMEM));
+/// assert_eq!(XenErrno::ENOMEM.to_linux(), Some(LinuxError::ENOMEM));
+/// assert_eq!(XenErrno(20).to_linux(), None);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct XenErrno(pub i32);
+
+xen_errno! {
+    EPERM = 1,
+    ENOENT = 2,
+    ESRCH = 3,
+    EINTR = 4,
+    EIO = 5,
+    ENXIO = 6,
+    E2BIG = 7,
+    ENOEXEC = 8,
+    EBADF = 9,
+    ECHILD = 10,
+    EAGAIN = 11,
+    ENOMEM = 12,
+    EACCES = 13,
+    EFAULT = 14,
+    EBUSY = 16,
+    EEXIST = 17,
+    EXDEV = 18,
+    ENODEV = 19,
+    EISDIR = 21,
+    EINVAL = 22,
+    ENFILE = 23,
+    EMFILE = 24,
+    ENOSPC = 28,
+    EROFS = 30,
+    EMLINK = 31,
+    EDOM = 33,
+    ERANGE = 34,
+    EDEADLK = 35,
+    ENAMETOOLONG = 36,
+    ENOLCK = 37,
+    ENOSYS = 38,
+    ENOTEMPTY = 39,
+    ENODATA = 61,
+    ETIME = 62,
+    EBADMSG = 74,
+    EOVERFLOW = 75,
+    EILSEQ = 84,
+    ERESTART = 85,
+    ENOTSOCK = 88,
+    EMSGSIZE = 90,
+    EOPNOTSUPP = 95,
+    EADDRINUSE = 98,
+    EADDRNOTAVAIL = 99,
+    ENOBUFS = 105,
+    EISCONN = 106,
+    ENOTCONN = 107,
+    ETIMEDOUT = 110,
+    ECONNREFUSED = 111,
+}
+
+impl XenErrno {
+    /// Converts the code into a [`LinuxError`].
+    ///
+    /// Returns `None` if Xen does not define the code, or if it is
+    /// [`ERESTART`](Self::ERESTART), which must not leak out of the
+    /// hypervisor.
+    pub fn to_linux(self) -> Option<LinuxError> {
+        if self == Self::ERESTART || self.name().is_none() {
+            return None;
+        }
+        LinuxError::try_from(self.0).ok()
+    }
+
+    /// Converts a [`LinuxError`] into a Xen code, if Xen defines it.
+    pub fn from_linux(err: LinuxError) -> Option<Self> {
+        let xen = Self(err.code());
+        (xen.name().is_some() && xen != Self::ERESTART).then_some(xen)
+    }
+}
+
+/// Errors Xen has no code for become [`EIO`](XenErrno::EIO).
+impl From<AxError> for XenErrno {
+    fn from(e: AxError) -> Self {
+        Self::from_linux(e.into()).unwrap_or(Self::EIO)
+    }
+}
+
+impl fmt::Debug for XenErrno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "XenErrno({})", self.0),
+        }
+    }
+}
+
+/// Decodes the raw return value of a hypercall.
+pub fn decode_hypercall(ret: isize) -> Result<usize, XenErrno> {
+    if ret >= 0 {
+        Ok(ret as usize)
+    } else {
+        Err(XenErrno(ret.unsigned_abs().min(i32::MAX as usize) as i32))
+    }
+}
+
+/// Decodes the raw return value of a hypercall into a [`LinuxResult`].
+///
+/// Codes rejected by [`XenErrno::to_linux`] become
+/// [`EIO`](LinuxError::EIO).
+pub fn hypercall_result(ret: isize) -> LinuxResult<usize> {
+    decode_hypercall(ret).map_err(|e| e.to_linux().unwrap_or(LinuxError::EIO))
+}
+
+/// Encodes a hypercall return value, e.g. in a backend emulating one.
+pub fn encode_hypercall(res: Result<usize, XenErrno>) -> isize {
+    match res {
+        Ok(v) => v.min(isize::MAX as usize) as isize,
+        Err(e) => -(e.0 as isize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypercall() {
+        assert_eq!(hypercall_result(-22), Err(LinuxError::EINVAL));
+        assert_eq!(hypercall_result(-85), Err(LinuxError::EIO));
+        assert_eq!(hypercall_result(-20), Err(LinuxError::EIO));
+        assert_eq!(decode_hypercall(isize::MIN), Err(XenErrno(i32::MAX)));
+
+        let ret = encode_hypercall(Err(XenErrno::EBUSY));
+        assert_eq!(ret, -16);
+        assert_eq!(decode_hypercall(ret), Err(XenErrno::EBUSY));
+        assert_eq!(encode_hypercall(Ok(7)), 7);
+
+        assert_eq!(XenErrno::from(AxError::NotADirectory), XenErrno::EIO);
+        assert_eq!(XenErrno::from(AxError::Timeout), XenErrno::ETIME);
+        assert_eq!(XenErrno::ERESTART.name(), Some("XEN_ERESTART"));
+    }
+}
@@ -0,0 +1,53 @@
+//! Checks the site IDs logged by the error macros with `compact-log`.
+
+#![cfg(all(feature = "compact-log", not(feature = "deferred-log")))]
+
+use std::sync::Mutex;
+
+use axerrno::site::site_id;
+use axerrno::{ax_err, raise, AxError, AxResult};
+use log::{Log, Metadata, Record};
+
+static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn test_compact_log() {
+    log::set_logger(&Capture).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let line = line!() + 1;
+    let res: AxResult = ax_err!(NotFound, "not formatted");
+    assert!(res.is_err());
+
+    let raised = line!() + 1;
+    let first = raise(AxError::Io);
+    let second = raise(AxError::Io);
+    assert_eq!((first, second), (AxError::Io, AxError::Io));
+
+    let id = site_id(file!(), line, 25);
+    let first = site_id(file!(), raised, 17);
+    let second = site_id(file!(), raised + 1, 18);
+    assert_ne!(first, second);
+    assert_eq!(
+        *RECORDS.lock().unwrap(),
+        [
+            format!("site {id:08x}: errno 2"),
+            format!("site {first:08x}: errno 5"),
+            format!("site {second:08x}: errno 5"),
+        ]
+    );
+}
@@ -0,0 +1,65 @@
+//! Checks that the error macros prefix their warnings with the task context.
+
+#![cfg(all(
+    feature = "log",
+    not(any(feature = "deferred-log", feature = "compact-log"))
+))]
+
+use std::sync::Mutex;
+
+use axerrno::context::{set_context_provider, ContextProvider, TaskContext};
+use axerrno::{ax_err, AxError, AxResult};
+use log::{Log, Metadata, Record};
+
+static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+struct Task;
+
+impl ContextProvider for Task {
+    fn context(&self) -> Option<TaskContext> {
+        Some(TaskContext {
+            pid: Some(7),
+            tid: Some(9),
+            syscall: Some(56),
+        })
+    }
+}
+
+#[test]
+fn test_context_prefix() {
+    log::set_logger(&Capture).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let res: AxResult = ax_err!(NotFound, "no context");
+    assert_eq!(res, Err(AxError::NotFound));
+    set_context_provider(&Task).unwrap();
+    let res: AxResult = ax_err!(NotFound, "with context");
+    assert_eq!(res, Err(AxError::NotFound));
+
+    let ctx = TaskContext {
+        tid: Some(9),
+        ..TaskContext::default()
+    };
+    assert_eq!(ctx.to_string(), "tid 9");
+    assert_eq!(
+        *RECORDS.lock().unwrap(),
+        [
+            "context: [AxError::NotFound] no context",
+            "[pid 7 tid 9 syscall 56] context: [AxError::NotFound] with context",
+        ]
+    );
+}
@@ -0,0 +1,20 @@
+//! Checks that the error macros record into the deferred ring instead of
+//! logging with the `deferred-log` feature.
+
+#![cfg(feature = "deferred-log")]
+
+use axerrno::{ax_err, deferred, AxError, AxResult};
+
+#[test]
+fn test_macros_record() {
+    let line = line!() + 1;
+    let res: AxResult = ax_err!(Timeout, "not formatted");
+    assert_eq!(res, Err(AxError::Timeout));
+
+    let mut records = Vec::new();
+    assert_eq!(deferred::drain(|e| records.push(e)), 0);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].err, AxError::Timeout);
+    assert_eq!(records[0].location.file(), file!());
+    assert_eq!(records[0].location.line(), line);
+}
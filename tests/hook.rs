@@ -0,0 +1,41 @@
+//! Checks that the error macros call the installed hook.
+
+use std::panic::Location;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use axerrno::hook::{clear_hook, set_hook};
+use axerrno::{ax_err_type, raise, AxError};
+
+static IO_ERRORS: AtomicUsize = AtomicUsize::new(0);
+static LAST_LINE: AtomicU32 = AtomicU32::new(0);
+
+fn count_io(err: AxError, location: &'static Location<'static>) {
+    assert_eq!(location.file(), file!());
+    LAST_LINE.store(location.line(), Ordering::Relaxed);
+    if err == AxError::Io {
+        IO_ERRORS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_hook() {
+    set_hook(count_io);
+    ax_err_type!(Io);
+    ax_err_type!(NotFound);
+    ax_err_type!(Io, "again");
+
+    #[track_caller]
+    fn not_found() -> AxError {
+        ax_err_type!(NotFound)
+    }
+    // Outlining the macros keeps the location of `#[track_caller]` callers.
+    let line = line!() + 1;
+    not_found();
+    assert_eq!(LAST_LINE.load(Ordering::Relaxed), line);
+    let line = line!() + 1;
+    raise(AxError::Io);
+    assert_eq!(LAST_LINE.load(Ordering::Relaxed), line);
+    clear_hook();
+    ax_err_type!(Io);
+    assert_eq!(IO_ERRORS.load(Ordering::Relaxed), 3);
+}
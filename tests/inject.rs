@@ -0,0 +1,28 @@
+//! Checks that the error macros consult the fault injection policy.
+
+#![cfg(feature = "fault-inject")]
+
+use axerrno::inject::{self, InjectRule, RuleSet, Trigger};
+use axerrno::{ax_err, fault_point, AxError, AxResult};
+
+static RULES: [InjectRule; 2] = [
+    InjectRule::new(AxError::Io, Trigger::Always).on_error(AxError::WouldBlock),
+    InjectRule::new(AxError::NoMemory, Trigger::EveryNth(2)),
+];
+static POLICY: RuleSet = RuleSet::new(&RULES, 0);
+
+fn alloc() -> AxResult<u32> {
+    fault_point!();
+    Ok(1)
+}
+
+#[test]
+fn test_injection() {
+    inject::set_policy(&POLICY).unwrap();
+    assert_eq!(inject::set_policy(&POLICY), Err(AxError::AlreadyExists));
+
+    let res: AxResult = ax_err!(WouldBlock);
+    assert_eq!(res, Err(AxError::Io));
+    assert_eq!(alloc(), Ok(1));
+    assert_eq!(alloc(), Err(AxError::NoMemory));
+}
@@ -0,0 +1,30 @@
+//! Checks that the error macros do not evaluate their message and fields
+//! unless the warning is printed.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axerrno::{ax_err, ax_err_type, AxError, AxResult};
+
+static EVALUATED: AtomicUsize = AtomicUsize::new(0);
+
+fn message() -> &'static str {
+    EVALUATED.fetch_add(1, Ordering::Relaxed);
+    "would block"
+}
+
+#[test]
+fn test_lazy_message() {
+    // No logger is installed, so the log level is `Off`.
+    #[cfg(feature = "log")]
+    log::set_max_level(log::LevelFilter::Off);
+    let fd = 3;
+    for _ in 0..10 {
+        let res: AxResult = ax_err!(WouldBlock, message());
+        assert_eq!(res, Err(AxError::WouldBlock));
+        assert_eq!(
+            ax_err_type!(Again, message(), fd = fd, reason = %message()),
+            AxError::Again
+        );
+    }
+    assert_eq!(EVALUATED.load(Ordering::Relaxed), 0);
+}
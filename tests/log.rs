@@ -0,0 +1,153 @@
+//! Checks the log records emitted by the error macros.
+
+#![cfg(all(
+    feature = "log",
+    not(any(feature = "deferred-log", feature = "compact-log"))
+))]
+
+use std::sync::{Mutex, Once};
+
+use axerrno::{ax_err, ax_err_type, AxError, AxResult};
+use log::kv::{self, Key, Value, VisitSource};
+use log::{Log, Metadata, Record};
+
+static RECORDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+static FIELDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+struct Fields(Vec<(String, String)>);
+
+impl<'kvs> VisitSource<'kvs> for Fields {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+}
+
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let entry = (record.target().to_string(), record.args().to_string());
+        RECORDS.lock().unwrap().push(entry);
+        if record.args().to_string().contains("fields") {
+            let mut fields = Fields(Vec::new());
+            record.key_values().visit(&mut fields).unwrap();
+            FIELDS.lock().unwrap().extend(fields.0);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the capturing logger and returns the records containing `needle`.
+fn records(needle: &str) -> Vec<(String, String)> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+    let records = RECORDS.lock().unwrap();
+    records
+        .iter()
+        .filter(|(_, msg)| msg.contains(needle))
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_log_target() {
+    records("");
+    let res: AxResult = ax_err!(NotFound, "target-default");
+    assert_eq!(res, Err(AxError::NotFound));
+    assert_eq!(ax_err_type!(target: "fs", Io, "target-fs"), AxError::Io);
+
+    assert_eq!(
+        records("target-default"),
+        [(
+            "axerrno".to_string(),
+            "log: [AxError::NotFound] target-default".to_string()
+        )]
+    );
+    assert_eq!(records("target-fs")[0].0, "fs");
+}
+
+#[test]
+fn test_log_once_and_ratelimited() {
+    records("");
+    for _ in 0..20 {
+        let res: AxResult = axerrno::ax_err_once!(Io, "once-only");
+        assert_eq!(res, Err(AxError::Io));
+        let res: AxResult = axerrno::ax_err_ratelimited!(Io, "limited");
+        assert_eq!(res, Err(AxError::Io));
+    }
+    assert_eq!(records("once-only").len(), 1);
+    assert_eq!(
+        records("limited").len(),
+        axerrno::ratelimit::DEFAULT_BURST as usize
+    );
+}
+
+#[test]
+fn test_log_try() {
+    records("");
+    fn convert() -> AxResult<u8> {
+        let res: Result<u8, AxError> = Err(AxError::Busy);
+        Ok(axerrno::ax_try!(target: "dev", res, "try-busy"))
+    }
+    assert_eq!(convert(), Err(AxError::Busy));
+    assert_eq!(
+        records("try-busy"),
+        [(
+            "dev".to_string(),
+            "log: [AxError::Busy] try-busy".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_log_err() {
+    use axerrno::{AxResultExt, LinuxError, LinuxResult, LinuxResultExt};
+
+    records("");
+    let res: AxResult<u8> = Err(AxError::Io);
+    let line = line!() + 1;
+    assert_eq!(res.tap_err_warn(), Err(AxError::Io));
+    let res: LinuxResult<u8> = Err(LinuxError::EIO);
+    assert_eq!(res.log_err(log::Level::Info), Err(LinuxError::EIO));
+    assert_eq!(AxResult::Ok(1).tap_err_warn(), Ok(1));
+
+    let needle = format!("{}:{line}:", file!());
+    let logged = records(&needle);
+    assert_eq!(logged.len(), 1);
+    assert!(logged[0].1.ends_with(": [AxError::Io]"));
+    assert_eq!(records("[LinuxError::EIO]").len(), 1);
+}
+
+#[test]
+fn test_log_fields() {
+    records("");
+    let path = "/bin/sh";
+    let res: AxResult = ax_err!(NotFound, "fields", path = %path, fd = 3, mode = ?Some(1),);
+    assert_eq!(res, Err(AxError::NotFound));
+
+    assert_eq!(
+        records("fields"),
+        [(
+            "axerrno".to_string(),
+            "log: [AxError::NotFound] fields".to_string()
+        )]
+    );
+    let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+    assert_eq!(
+        *FIELDS.lock().unwrap(),
+        [
+            pair("path", "/bin/sh"),
+            pair("fd", "3"),
+            pair("mode", "Some(1)")
+        ]
+    );
+}
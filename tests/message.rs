@@ -0,0 +1,29 @@
+//! Checks that a registered message provider overrides the built-in messages.
+
+use axerrno::message::{set_message_provider, MessageProvider};
+use axerrno::{AxError, Errno, LinuxError};
+
+struct German;
+
+impl MessageProvider for German {
+    fn ax_message(&self, err: AxError) -> Option<&'static str> {
+        (err == AxError::NotFound).then_some("Nicht gefunden")
+    }
+
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        (err == LinuxError::EACCES).then_some("Keine Berechtigung")
+    }
+}
+
+#[test]
+fn test_message_provider() {
+    assert_eq!(AxError::NotFound.to_string(), "Entity not found");
+    set_message_provider(&German).unwrap();
+    assert_eq!(set_message_provider(&German), Err(AxError::AlreadyExists));
+
+    assert_eq!(AxError::NotFound.to_string(), "Nicht gefunden");
+    assert_eq!(AxError::Io.to_string(), AxError::Io.as_str());
+    assert_eq!(LinuxError::EACCES.message(), "Keine Berechtigung");
+    assert_eq!(Errno(13).to_string(), "Keine Berechtigung");
+    assert_eq!(LinuxError::EIO.to_string(), LinuxError::EIO.as_str());
+}
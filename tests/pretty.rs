@@ -0,0 +1,18 @@
+//! Checks the ANSI-colored rendering of errors.
+
+#![cfg(feature = "pretty")]
+
+use axerrno::{AxError, LinuxError};
+
+#[test]
+fn test_pretty() {
+    assert_eq!(
+        LinuxError::ENOENT.pretty().to_string(),
+        format!("\x1b[1;31mENOENT\x1b[0m: {}", LinuxError::ENOENT)
+    );
+    assert_eq!(
+        AxError::Busy.pretty().to_string(),
+        "\x1b[1;31mBusy\x1b[0m: Device or resource busy"
+    );
+    assert_eq!(AxError::Busy.pretty().into_inner(), AxError::Busy);
+}
@@ -0,0 +1,36 @@
+//! Checks that the error macros emit events to the registered sink.
+
+use std::sync::Mutex;
+
+use axerrno::sink::{self, ErrorEvent, ErrorSink};
+use axerrno::{ax_err, ax_err_once, AxError, AxResult};
+
+struct Recorder(Mutex<Vec<ErrorEvent>>);
+
+impl ErrorSink for Recorder {
+    fn record(&self, event: &ErrorEvent) {
+        self.0.lock().unwrap().push(*event);
+    }
+}
+
+static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+
+#[test]
+fn test_sink() {
+    sink::set_sink(&RECORDER).unwrap();
+
+    let line = line!() + 1;
+    let res: AxResult = ax_err!(NotFound);
+    assert_eq!(res, Err(AxError::NotFound));
+    for _ in 0..2 {
+        let res: AxResult = ax_err_once!(Busy);
+        assert_eq!(res, Err(AxError::Busy));
+    }
+
+    let events = RECORDER.0.lock().unwrap();
+    let errs: Vec<_> = events.iter().map(|e| e.err).collect();
+    assert_eq!(errs, [AxError::NotFound, AxError::Busy, AxError::Busy]);
+    assert_eq!(events[0].location.file(), file!());
+    assert_eq!(events[0].location.line(), line);
+    assert_eq!(events[0].timestamp, None);
+}
@@ -0,0 +1,38 @@
+//! Checks the counters bumped by the error macros.
+
+#![cfg(feature = "stats")]
+
+use axerrno::stats::{count, last_seen, render_stats, returned};
+use axerrno::{ax_err_type, dump_table, syscall_result, AxError, LinuxError, LinuxResult};
+
+#[test]
+fn test_render_stats() {
+    let line = line!() + 2;
+    for _ in 0..3 {
+        ax_err_type!(HardwareMemoryError);
+    }
+    assert_eq!(count(AxError::HardwareMemoryError), 3);
+    let loc = last_seen(AxError::HardwareMemoryError).unwrap();
+    assert_eq!((loc.file(), loc.line()), (file!(), line));
+
+    let mut out = String::new();
+    render_stats(&mut out).unwrap();
+    let expected = format!("HardwareMemoryError 3 {loc}\n");
+    assert!(out.ends_with(&expected), "{out}");
+    assert_eq!(last_seen(AxError::NoMedium), None);
+}
+
+#[test]
+fn test_returned() {
+    let before = returned(LinuxError::ENOTTY);
+    let res: LinuxResult<usize> = Err(LinuxError::ENOIOCTLCMD);
+    syscall_result!(ioctl, quiet, res);
+    assert_eq!(returned(LinuxError::ENOTTY), before + 1);
+    assert_eq!(returned(LinuxError::ENOIOCTLCMD), 0);
+
+    let mut out = String::new();
+    dump_table(&mut out).unwrap();
+    let line = out.lines().find(|l| l.contains(" ENOTTY ")).unwrap();
+    let count: u64 = line.split_whitespace().nth(2).unwrap().parse().unwrap();
+    assert!(count > before);
+}
@@ -0,0 +1,27 @@
+//! Compares the messages of [`LinuxError`] against glibc's `strerror(3)`.
+
+#![cfg(all(target_os = "linux", target_env = "gnu"))]
+
+use std::ffi::CStr;
+
+use axerrno::LinuxError;
+
+fn glibc_strerror(code: i32) -> String {
+    // SAFETY: `strerror` returns a valid NUL-terminated string. The tests in
+    // this file do not call it concurrently.
+    unsafe { CStr::from_ptr(libc::strerror(code)) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn test_strerror_matches_glibc() {
+    let errs = (1..4096).filter_map(|code| LinuxError::try_from(code).ok());
+    for err in errs.filter(|err| err.is_known() && !err.is_kernel_internal()) {
+        let expected = glibc_strerror(err.code());
+        assert_eq!(err.strerror(), expected, "`{err:?}::strerror` drifted");
+        if cfg!(feature = "glibc-strerror") {
+            assert_eq!(err.as_str(), expected, "`{err:?}::as_str` drifted");
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Checks reading error codes from bytes with `zerocopy`.
+
+#![cfg(feature = "zerocopy")]
+
+use axerrno::{AxError, Errno, LinuxError};
+use zerocopy::{FromBytes, IntoBytes, TryFromBytes};
+
+#[test]
+fn test_zerocopy() {
+    let bytes = 2_i32.to_ne_bytes();
+    assert_eq!(
+        AxError::try_read_from_bytes(&bytes),
+        Ok(AxError::AlreadyExists)
+    );
+    assert!(AxError::try_read_from_bytes(&[0; 4]).is_err());
+    assert_eq!(AxError::Busy.as_bytes(), 26_i32.to_ne_bytes());
+
+    let errno = Errno::read_from_bytes(LinuxError::ENOENT.as_bytes()).unwrap();
+    assert_eq!(errno.linux(), Some(LinuxError::ENOENT));
+    assert_eq!(Errno::read_from_bytes(&[0; 4]).unwrap().linux(), None);
+}